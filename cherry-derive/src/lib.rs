@@ -0,0 +1,733 @@
+//! `cherry-derive`: `#[derive(Cherry)]` companion macro.
+//!
+//! Building an `Action` by hand means a long chain of `insert_field`/
+//! `insert_flag`/`insert_argument` calls. This crate lets a struct describe
+//! that same shape declaratively instead:
+//!
+//! ```ignore
+//! use cherry_derive::Cherry;
+//!
+//! /// Serve files from a directory.
+//! #[derive(Cherry)]
+//! struct Serve {
+//!     /// The port to listen on.
+//!     #[cherry(short = 'p', default = "8080")]
+//!     port: i64,
+//!
+//!     /// Enable verbose logging.
+//!     #[cherry(short = 'v')]
+//!     verbose: bool,
+//!
+//!     /// Files to serve.
+//!     files: Vec<String>,
+//! }
+//! ```
+//!
+//! `#[derive(Cherry)]` maps each field to the Action piece its type already
+//! matches an existing builder for, rather than inventing a new attribute
+//! vocabulary: a `bool` field becomes a `Flag`, a `Vec<String>` field
+//! becomes the Action's (single, trailing) positional `Argument` list, an
+//! `Option<T>` field becomes a non-required `Field`, and everything else
+//! becomes a required `Field`. Doc comments populate `description`, the
+//! same way they already double as rustdoc; `#[cherry(...)]` only needs to
+//! carry what a doc comment can't: `short`, `long` (a title override),
+//! `default`, and an explicit `description` when the field has no doc
+//! comment of its own.
+//!
+//! The derive emits two associated functions rather than trying to
+//! implement a shared trait: `action(keyword: &str) -> cherry::Result<cherry::Action<H>>`
+//! builds the Action (generic over the handler's return type `H`, left for
+//! the caller to pick when they attach `.then(...)`), and
+//! `from_request(request: &cherry::Request<H>) -> cherry::Result<Self>`
+//! pulls the fields back out of a parsed Request. Struct-level positional
+//! arguments beyond a single trailing `Vec<String>` are out of scope for
+//! this first pass, the same way `Argument::conflicts_with`/`requires` was
+//! scoped out in the hand-written builder API: `Request`'s Argument storage
+//! is a plain `Vec<String>`, not title-keyed, so per-field positional
+//! attributes have nowhere consistent to attach.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, GenericArgument, LitChar, LitStr, PathArguments, Type};
+
+/// Derive an `Action` builder and `Request` extractor for a struct.
+///
+/// See the crate documentation for the field-to-builder mapping and the
+/// `#[cherry(...)]` attributes recognised on each field.
+#[proc_macro_derive(Cherry, attributes(cherry))]
+pub fn derive_cherry(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expand a `#[derive(Cherry)]` invocation into the `action`/`from_request`
+/// associated functions, or a `syn::Error` describing the unsupported
+/// shape.
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let description = doc_comment(&input.attrs);
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "#[derive(Cherry)] requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "#[derive(Cherry)] only supports structs",
+            ))
+        }
+    };
+
+    let mut builders = Vec::new();
+    let mut extractors = Vec::new();
+    let mut argument_field_seen = false;
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field has an ident");
+        let attribute = FieldAttribute::parse(&field.attrs)?;
+        let title = attribute.long.clone().unwrap_or_else(|| ident.to_string());
+        let description = attribute
+            .description
+            .clone()
+            .unwrap_or_else(|| doc_comment(&field.attrs));
+
+        let shape = Shape::classify(&field.ty).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &field.ty,
+                "#[derive(Cherry)] does not know how to map this field type; \
+                 supported shapes are bool, Vec<String>, Option<T>, and T for \
+                 String/i64/i32/i16/i8/u64/u32/u16/u8/isize/usize/f64/f32",
+            )
+        })?;
+
+        match shape {
+            Shape::Flag => {
+                let short = attribute
+                    .short
+                    .map(|short| quote! { .short(#short) })
+                    .unwrap_or_default();
+                builders.push(quote! {
+                    action = action.insert_flag(
+                        cherry::Flag::new(#title)?
+                            .description(#description)
+                            #short
+                    )?;
+                });
+                extractors.push(quote! {
+                    #ident: request.get_flag(#title).copied().unwrap_or(false)
+                });
+            }
+            Shape::Arguments => {
+                if argument_field_seen {
+                    return Err(syn::Error::new_spanned(
+                        &field.ty,
+                        "#[derive(Cherry)] only supports a single Vec<String> (positional \
+                         arguments) field per struct",
+                    ));
+                }
+                argument_field_seen = true;
+                builders.push(quote! {
+                    action = action.insert_argument(
+                        cherry::Argument::new(#title)?
+                            .description(#description)
+                            .multiple(true)
+                    )?;
+                });
+                extractors.push(quote! {
+                    #ident: request.get_argument_values(0).to_vec()
+                });
+            }
+            Shape::Field { kind, required } => {
+                let short = attribute
+                    .short
+                    .map(|short| quote! { .short(#short) })
+                    .unwrap_or_default();
+                let default = attribute
+                    .default
+                    .as_ref()
+                    .map(|default| quote! { .default(#default) })
+                    .unwrap_or_default();
+                let required_builder = if required && attribute.default.is_none() {
+                    quote! { .required() }
+                } else {
+                    quote! {}
+                };
+
+                builders.push(quote! {
+                    action = action.insert_field(
+                        cherry::Field::new(#title)?
+                            .kind(#kind)
+                            .description(#description)
+                            #short
+                            #default
+                            #required_builder
+                    )?;
+                });
+                extractors.push(kind.extractor(ident, &title, required));
+            }
+        }
+    }
+
+    let action_description = if description.is_empty() {
+        quote! {}
+    } else {
+        quote! { action = action.description(#description); }
+    };
+
+    Ok(quote! {
+        impl #name {
+            /// Build the Action this struct's fields describe.
+            ///
+            /// Generated by `#[derive(Cherry)]`. See the `cherry_derive`
+            /// crate documentation for the field-to-builder mapping.
+            ///
+            /// # Error
+            /// Will error if any of the generated builder calls do, e.g. a
+            /// duplicate Field/Flag/Argument title.
+            pub fn action<H>(keyword: &str) -> cherry::Result<cherry::Action<H>> {
+                #[allow(unused_mut)]
+                let mut action = cherry::Action::new(keyword)?;
+                #action_description
+                #(#builders)*
+                Ok(action)
+            }
+
+            /// Extract this struct's fields back out of a parsed Request.
+            ///
+            /// Generated by `#[derive(Cherry)]`. See the `cherry_derive`
+            /// crate documentation for the field-to-extractor mapping.
+            ///
+            /// # Error
+            /// Will error if a required Field is missing or could not be
+            /// converted to its declared type.
+            pub fn from_request<H>(request: &cherry::Request<H>) -> cherry::Result<Self> {
+                Ok(Self {
+                    #(#extractors),*
+                })
+            }
+        }
+    })
+}
+
+/// The recognised `#[cherry(...)]` attribute contents for a single field.
+#[derive(Default)]
+struct FieldAttribute {
+    /// `short = 'x'`: the Field/Flag's short form.
+    short: Option<LitChar>,
+    /// `long = "..."`: overrides the title, which otherwise defaults to the
+    /// field's own identifier.
+    long: Option<String>,
+    /// `default = "..."`: the Field's default value.
+    default: Option<String>,
+    /// `description = "..."`: overrides the doc comment as the
+    /// Field/Flag/Argument's description.
+    description: Option<String>,
+}
+
+impl FieldAttribute {
+    /// Parse every `#[cherry(...)]` attribute on a field into one
+    /// `FieldAttribute`.
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("cherry") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("short") {
+                    parsed.short = Some(meta.value()?.parse::<LitChar>()?);
+                } else if meta.path.is_ident("long") {
+                    parsed.long = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    parsed.default = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("description") {
+                    parsed.description = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    return Err(meta.error("unrecognised #[cherry(...)] attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// The Action piece a field's type maps onto.
+enum Shape {
+    /// A `bool` field: becomes a `Flag`.
+    Flag,
+    /// A `Vec<String>` field: becomes the Action's positional, `multiple`
+    /// `Argument` list.
+    Arguments,
+    /// Any other recognised field type: becomes a `Field` of the given
+    /// `FieldType`, required unless `required` is false (an `Option<T>`
+    /// field).
+    Field { kind: FieldKind, required: bool },
+}
+
+impl Shape {
+    /// Classify a field's type into the Action piece it maps onto, or
+    /// `None` if the type isn't one `#[derive(Cherry)]` recognises.
+    fn classify(ty: &Type) -> Option<Self> {
+        let (name, generic) = type_name(ty)?;
+
+        match name.as_str() {
+            "bool" => Some(Self::Flag),
+            "Vec" => {
+                let inner = generic?;
+                let (inner_name, _) = type_name(inner)?;
+                (inner_name == "String").then_some(Self::Arguments)
+            }
+            "Option" => {
+                let inner = generic?;
+                let (inner_name, _) = type_name(inner)?;
+                Some(Self::Field {
+                    kind: FieldKind::from_type_name(&inner_name)?,
+                    required: false,
+                })
+            }
+            _ => Some(Self::Field {
+                kind: FieldKind::from_type_name(&name)?,
+                required: true,
+            }),
+        }
+    }
+}
+
+/// The `FieldType`/retrieval-type pair a non-bool, non-Vec field maps onto.
+#[derive(Clone, Copy)]
+enum FieldKind {
+    /// Maps to `FieldType::String`, retrieved as `String`.
+    String,
+    /// Maps to `FieldType::Integer`, retrieved as `i64` then cast to the
+    /// field's own integer type.
+    Integer,
+    /// Maps to `FieldType::Float`, retrieved as `f64` then cast to the
+    /// field's own float type.
+    Float,
+}
+
+impl FieldKind {
+    /// Match a type's bare name (e.g. `"i64"`) to the `FieldKind` it maps
+    /// onto, if any.
+    fn from_type_name(name: &str) -> Option<Self> {
+        match name {
+            "String" => Some(Self::String),
+            "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => {
+                Some(Self::Integer)
+            }
+            "f32" | "f64" => Some(Self::Float),
+            _ => None,
+        }
+    }
+
+    /// Build the extraction expression for a field of this kind, casting
+    /// back to the field's own declared type where the intermediate
+    /// retrieval type differs (every integer/float width funnels through
+    /// `i64`/`f64`, the only widths `FieldType` stores). An `Option<T>`
+    /// field (`required` false) falls back to `None` on a
+    /// `MissingFieldValue` rather than propagating it.
+    fn extractor(self, ident: &syn::Ident, title: &str, required: bool) -> TokenStream2 {
+        let (retrieved, cast) = match self {
+            Self::String => (quote! { String }, quote! {}),
+            Self::Integer => (quote! { i64 }, quote! { as _ }),
+            Self::Float => (quote! { f64 }, quote! { as _ }),
+        };
+
+        if required {
+            quote! { #ident: request.field_as::<#retrieved>(#title)? #cast }
+        } else {
+            quote! {
+                #ident: match request.field_as::<#retrieved>(#title) {
+                    Ok(value) => Some(value #cast),
+                    Err(cherry::Error::MissingFieldValue { .. }) => None,
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+    }
+}
+
+impl quote::ToTokens for FieldKind {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::String => quote! { cherry::FieldType::String },
+            Self::Integer => quote! { cherry::FieldType::Integer },
+            Self::Float => quote! { cherry::FieldType::Float },
+        });
+    }
+}
+
+/// Extract a type's bare name and, if present, its first generic argument
+/// (e.g. `Option<i64>` yields `("Option", Some(&i64 type))`).
+fn type_name(ty: &Type) -> Option<(String, Option<&Type>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let name = segment.ident.to_string();
+
+    let generic = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments.args.iter().find_map(|argument| {
+            if let GenericArgument::Type(ty) = argument {
+                Some(ty)
+            } else {
+                None
+            }
+        }),
+        _ => None,
+    };
+
+    Some((name, generic))
+}
+
+/// Join a field or struct's `///` doc comment lines into a single
+/// description string, trimming each line's leading space.
+///
+/// Returns an empty string if there is no doc comment, so callers can fall
+/// back to an explicit `#[cherry(description = "...")]` or omit the
+/// description entirely.
+fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                syn::Expr::Lit(expr) => match &expr.lit {
+                    syn::Lit::Str(lit) => Some(lit.value().trim().to_owned()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use syn::{parse_quote, DeriveInput};
+
+    /// Shape::classify must map a bool field to Flag.
+    ///
+    /// The classify method must recognise a bare `bool` type as the Flag
+    /// shape.
+    #[test]
+    fn shape_classify_bool_is_flag() {
+        let ty: Type = parse_quote! { bool };
+        assert!(matches!(Shape::classify(&ty), Some(Shape::Flag)));
+    }
+
+    /// Shape::classify must map a Vec<String> field to Arguments.
+    ///
+    /// The classify method must recognise `Vec<String>` as the positional
+    /// Arguments shape.
+    #[test]
+    fn shape_classify_vec_string_is_arguments() {
+        let ty: Type = parse_quote! { Vec<String> };
+        assert!(matches!(Shape::classify(&ty), Some(Shape::Arguments)));
+    }
+
+    /// Shape::classify must reject a Vec of anything other than String.
+    ///
+    /// The classify method must not map Vec<i64> to Arguments, since
+    /// Request's Argument storage is a plain Vec<String>.
+    #[test]
+    fn shape_classify_vec_non_string_is_none() {
+        let ty: Type = parse_quote! { Vec<i64> };
+        assert!(Shape::classify(&ty).is_none());
+    }
+
+    /// Shape::classify must map an Option<T> field to a non-required Field.
+    ///
+    /// The classify method must recognise `Option<i64>` as an Integer Field
+    /// that is not required.
+    #[test]
+    fn shape_classify_option_is_non_required_field() {
+        let ty: Type = parse_quote! { Option<i64> };
+        assert!(matches!(
+            Shape::classify(&ty),
+            Some(Shape::Field {
+                kind: FieldKind::Integer,
+                required: false,
+            })
+        ));
+    }
+
+    /// Shape::classify must map a bare scalar field to a required Field.
+    ///
+    /// The classify method must recognise `String` as a required String
+    /// Field.
+    #[test]
+    fn shape_classify_string_is_required_field() {
+        let ty: Type = parse_quote! { String };
+        assert!(matches!(
+            Shape::classify(&ty),
+            Some(Shape::Field {
+                kind: FieldKind::String,
+                required: true,
+            })
+        ));
+    }
+
+    /// Shape::classify must return None for an unrecognised type.
+    ///
+    /// The classify method must not map a type with no corresponding
+    /// FieldKind or builtin shape.
+    #[test]
+    fn shape_classify_unsupported_type_is_none() {
+        let ty: Type = parse_quote! { std::net::IpAddr };
+        assert!(Shape::classify(&ty).is_none());
+    }
+
+    /// FieldKind::from_type_name must map every supported integer width.
+    ///
+    /// The from_type_name method must recognise every signed/unsigned
+    /// integer width as the Integer FieldKind, since they all funnel
+    /// through i64 at retrieval time.
+    #[test]
+    fn field_kind_from_type_name_integer_widths() {
+        for name in ["i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize"] {
+            assert!(matches!(
+                FieldKind::from_type_name(name),
+                Some(FieldKind::Integer)
+            ));
+        }
+    }
+
+    /// FieldKind::from_type_name must map every supported float width.
+    ///
+    /// The from_type_name method must recognise f32 and f64 as the Float
+    /// FieldKind.
+    #[test]
+    fn field_kind_from_type_name_float_widths() {
+        for name in ["f32", "f64"] {
+            assert!(matches!(
+                FieldKind::from_type_name(name),
+                Some(FieldKind::Float)
+            ));
+        }
+    }
+
+    /// FieldKind::from_type_name must return None for an unrecognised name.
+    ///
+    /// The from_type_name method must not map a type name it has no
+    /// FieldType counterpart for.
+    #[test]
+    fn field_kind_from_type_name_unknown() {
+        assert!(FieldKind::from_type_name("bool").is_none());
+    }
+
+    /// FieldKind::extractor must cast a narrower integer width back with `as _`.
+    ///
+    /// The extractor method must emit an `as _` cast after `field_as::<i64>`
+    /// for a required Integer Field, so a narrower field type (e.g. i32)
+    /// still compiles.
+    #[test]
+    fn field_kind_extractor_required_integer_casts() {
+        let ident: syn::Ident = parse_quote! { port };
+        let tokens = FieldKind::Integer.extractor(&ident, "port", true).to_string();
+
+        assert!(tokens.contains("field_as :: < i64 > ("));
+        assert!(tokens.contains("as _"));
+    }
+
+    /// FieldKind::extractor must fall back to None for an optional Field.
+    ///
+    /// The extractor method must turn a MissingFieldValue Error into None
+    /// for a non-required (Option<T>) Field, rather than propagating it.
+    #[test]
+    fn field_kind_extractor_optional_falls_back_to_none() {
+        let ident: syn::Ident = parse_quote! { port };
+        let tokens = FieldKind::Integer.extractor(&ident, "port", false).to_string();
+
+        assert!(tokens.contains("MissingFieldValue"));
+        assert!(tokens.contains("None"));
+    }
+
+    /// FieldAttribute::parse must read short, long, default and description.
+    ///
+    /// The parse method must populate every recognised #[cherry(...)] key
+    /// from a field's attributes.
+    #[test]
+    fn field_attribute_parse_all_keys() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! {
+            #[cherry(short = 'p', long = "port-number", default = "8080", description = "The port.")]
+        }];
+        let parsed = FieldAttribute::parse(&attrs).unwrap();
+
+        assert_eq!(Some('p'), parsed.short.map(|short| short.value()));
+        assert_eq!(Some(String::from("port-number")), parsed.long);
+        assert_eq!(Some(String::from("8080")), parsed.default);
+        assert_eq!(Some(String::from("The port.")), parsed.description);
+    }
+
+    /// FieldAttribute::parse must reject an unrecognised key.
+    ///
+    /// The parse method must error when a #[cherry(...)] attribute carries a
+    /// key it doesn't recognise.
+    #[test]
+    fn field_attribute_parse_unknown_key() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! {
+            #[cherry(unknown = "value")]
+        }];
+
+        assert!(FieldAttribute::parse(&attrs).is_err());
+    }
+
+    /// doc_comment must join multiple doc lines with a space.
+    ///
+    /// The doc_comment function must trim each `///` line's leading space
+    /// and join them into a single description string.
+    #[test]
+    fn doc_comment_joins_multiple_lines() {
+        let input: DeriveInput = parse_quote! {
+            /// First line.
+            /// Second line.
+            struct Serve;
+        };
+
+        assert_eq!("First line. Second line.", doc_comment(&input.attrs));
+    }
+
+    /// doc_comment must return an empty string when there is no doc comment.
+    ///
+    /// The doc_comment function must not fail on a field or struct with no
+    /// `///` attributes, so callers can fall back to an explicit
+    /// description.
+    #[test]
+    fn doc_comment_empty_without_doc() {
+        let input: DeriveInput = parse_quote! {
+            struct Serve;
+        };
+
+        assert_eq!("", doc_comment(&input.attrs));
+    }
+
+    /// expand must reject an enum.
+    ///
+    /// The expand function must only support structs, so deriving on an
+    /// enum must error.
+    #[test]
+    fn expand_rejects_enum() {
+        let input: DeriveInput = parse_quote! {
+            enum Serve { Up, Down }
+        };
+
+        let error = expand(input).unwrap_err();
+        assert!(error.to_string().contains("only supports structs"));
+    }
+
+    /// expand must reject a tuple struct.
+    ///
+    /// The expand function must only support structs with named fields, so
+    /// deriving on a tuple struct must error.
+    #[test]
+    fn expand_rejects_tuple_struct() {
+        let input: DeriveInput = parse_quote! {
+            struct Serve(i64);
+        };
+
+        let error = expand(input).unwrap_err();
+        assert!(error.to_string().contains("requires a struct with named fields"));
+    }
+
+    /// expand must reject a field type it doesn't recognise.
+    ///
+    /// The expand function must error, naming the offending field's type,
+    /// when a field's type has no corresponding Shape.
+    #[test]
+    fn expand_rejects_unsupported_field_type() {
+        let input: DeriveInput = parse_quote! {
+            struct Serve {
+                address: std::net::IpAddr,
+            }
+        };
+
+        let error = expand(input).unwrap_err();
+        assert!(error.to_string().contains("does not know how to map this field type"));
+    }
+
+    /// expand must reject more than one Vec<String> field.
+    ///
+    /// The expand function must error on a second positional Arguments
+    /// field, since only one variadic positional field is supported per
+    /// struct.
+    #[test]
+    fn expand_rejects_multiple_argument_fields() {
+        let input: DeriveInput = parse_quote! {
+            struct Serve {
+                files: Vec<String>,
+                extras: Vec<String>,
+            }
+        };
+
+        let error = expand(input).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("only supports a single Vec<String>"));
+    }
+
+    /// expand must accept a struct mixing every supported field shape.
+    ///
+    /// The expand function must succeed for a struct combining a Flag, a
+    /// required Field, a non-required Field and the positional Arguments
+    /// field, emitting the action/from_request associated functions.
+    #[test]
+    fn expand_accepts_every_shape() {
+        let input: DeriveInput = parse_quote! {
+            /// Serve files from a directory.
+            struct Serve {
+                #[cherry(short = 'p', default = "8080")]
+                port: i64,
+                #[cherry(short = 'v')]
+                verbose: bool,
+                timeout: Option<f64>,
+                files: Vec<String>,
+            }
+        };
+
+        let tokens = expand(input).unwrap().to_string();
+
+        assert!(tokens.contains("fn action"));
+        assert!(tokens.contains("fn from_request"));
+        assert!(tokens.contains("cherry :: FieldType :: Integer"));
+        assert!(tokens.contains("cherry :: FieldType :: Float"));
+        assert!(tokens.contains("insert_flag"));
+        assert!(tokens.contains("insert_argument"));
+        assert!(tokens.contains("Serve files from a directory."));
+    }
+
+    /// expand must omit the action description when the struct has none.
+    ///
+    /// The expand function must not emit an `action.description(...)` call
+    /// when the struct carries no doc comment.
+    #[test]
+    fn expand_omits_description_when_absent() {
+        let input: DeriveInput = parse_quote! {
+            struct Serve {
+                verbose: bool,
+            }
+        };
+
+        let tokens = expand(input).unwrap().to_string();
+        assert!(!tokens.contains("action = action . description"));
+    }
+}
@@ -6,10 +6,63 @@
 
 use crate::error::{self, Error};
 use core::cmp::Ordering;
-use std::collections::HashMap;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug, Formatter};
 use std::rc::Rc;
 
+/// Render a possible_values set as a help text suffix.
+///
+/// Produces a trailing `" [possible values: a, b, c]"` fragment for an
+/// ARGUMENTS/FIELDS help line, or an empty string if no possible values are
+/// set.
+fn possible_values_suffix(possible_values: &Option<Vec<(String, Option<String>)>>) -> String {
+    possible_values.as_ref().map_or_else(String::new, |values| {
+        let names: Vec<&str> = values.iter().map(|(value, _)| value.as_str()).collect();
+        format!(" [possible values: {}]", names.join(", "))
+    })
+}
+
+/// Render a possible_values set's per-value descriptions.
+///
+/// Produces one indented `name  description` line per value that carries a
+/// description, or an empty string if none do.
+fn possible_value_descriptions(possible_values: &Option<Vec<(String, Option<String>)>>) -> String {
+    possible_values.as_ref().map_or_else(String::new, |values| {
+        values
+            .iter()
+            .filter_map(|(value, description)| {
+                description
+                    .as_ref()
+                    .map(|description| format!("        {value:<16}{description}\n"))
+            })
+            .collect()
+    })
+}
+
+/// Render a possible_values set as a zsh `_arguments` value spec.
+///
+/// Produces a `(a b c)` choice list for zsh to offer as completions, or an
+/// empty string if no possible values are set, leaving the value unconstrained.
+fn zsh_value_choices(possible_values: &Option<Vec<(String, Option<String>)>>) -> String {
+    possible_values.as_ref().map_or_else(String::new, |values| {
+        let names: Vec<&str> = values.iter().map(|(value, _)| value.as_str()).collect();
+        format!("({})", names.join(" "))
+    })
+}
+
+/// Check a supplied value against a `possible_values` set.
+///
+/// Compares byte for byte, unless `case_insensitive` is set, in which case
+/// the comparison folds ASCII and Unicode case.
+fn matches_possible_value(value: &str, name: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        value.to_lowercase() == name.to_lowercase()
+    } else {
+        value == name
+    }
+}
+
 /// Action<T>.
 ///
 /// Actions are the customised application specific commands. Actions are
@@ -74,12 +127,26 @@ use std::rc::Rc;
 ///
 /// ## Parent and child actions
 /// ```rust
-/// // Todo(Paul): When actions have parent-child relationships.
+/// use cherry::Action;
+///
+/// fn main() -> cherry::Result<()> {
+///     let action = Action::<()>::new("my_action")?
+///         .insert_child(Action::new("my_child")?)?;
+///     Ok(())
+/// }
 /// ```
 ///
 /// ## Abstract parent action
 /// ```rust
-/// // Todo(Paul): When actions have parent-child relationships.
+/// use cherry::Action;
+///
+/// fn main() -> cherry::Result<()> {
+///     // Omitting a call to `then` leaves the Action abstract; it simply
+///     // houses child Actions and cannot be run on its own.
+///     let action = Action::<()>::new("my_action")?
+///         .insert_child(Action::new("my_child")?.then(|_| ()))?;
+///     Ok(())
+/// }
 /// ```
 pub struct Action<T> {
     /// The keyword to invoke this Action.
@@ -97,6 +164,17 @@ pub struct Action<T> {
     /// The Flags this Action accepts.
     flags: HashMap<String, Flag>,
 
+    /// The child Actions nested under this Action, keyed by keyword.
+    children: HashMap<String, Action<T>>,
+
+    /// The Groups declaring relationships among this Action's Fields and
+    /// Flags, in declaration order.
+    groups: Vec<Group>,
+
+    /// The keywords of prerequisite Actions that `Cherry::dispatch` must run
+    /// before this Action, in declaration order.
+    requires: Vec<String>,
+
     /// The callback method attached to the Action.
     then: Option<Box<dyn Fn(Request<T>) -> T>>,
 }
@@ -130,6 +208,9 @@ impl<T> Action<T> {
             arguments: Vec::new(),
             fields: HashMap::new(),
             flags: HashMap::new(),
+            children: HashMap::new(),
+            groups: Vec::new(),
+            requires: Vec::new(),
             then: None,
         })
     }
@@ -172,12 +253,20 @@ impl<T> Action<T> {
     ///
     /// # Error
     /// Errors occur if attempting to insert an Argument with a blank (empty)
-    /// title.
+    /// title, or if the last Argument already inserted is `multiple`, as a
+    /// variadic Argument must be the last one declared.
     pub fn insert_argument(mut self, argument: Argument) -> error::Result<Self> {
         if argument.title.is_empty() {
             return Err(Error::new("Argument must have a non-empty title."));
         }
 
+        if self.arguments.last().map_or(false, |last| last.multiple) {
+            return Err(Error::new(&format!(
+                "Argument '{}' cannot follow a multiple-valued Argument.",
+                argument.title
+            )));
+        }
+
         self.arguments.push(argument);
         Ok(self)
     }
@@ -200,7 +289,8 @@ impl<T> Action<T> {
     /// # Error
     /// Errors occur if attempting to insert a Field with a blank (empty)
     /// title. Will also error if sharing a title or short with a Flag or existing
-    /// Field.
+    /// Field, or if the Field's `default` is set to a value outside its own
+    /// `possible_values` set.
     pub fn insert_field(mut self, field: Field) -> error::Result<Self> {
         if field.title.is_empty() {
             return Err(Error::new("Field must have a non-empty title."));
@@ -237,6 +327,16 @@ impl<T> Action<T> {
             }
         }
 
+        if let (Some(default), Some(choices)) = (&field.default, &field.possible_values) {
+            let names: Vec<String> = choices.iter().map(|(name, _)| name.clone()).collect();
+            if !names
+                .iter()
+                .any(|name| matches_possible_value(default, name, field.case_insensitive))
+            {
+                return Err(Error::invalid_choice(&field.title, default, &names));
+            }
+        }
+
         if let Some(short) = field.short {
             self.fields.insert(String::from(short), field.clone());
         }
@@ -306,6 +406,539 @@ impl<T> Action<T> {
         Ok(self)
     }
 
+    /// Insert a child Action into the Action.
+    ///
+    /// Insert a child Action onto the Action object. Child Actions are selected
+    /// by keyword immediately following their parent's keyword, allowing
+    /// Actions to be organised into a navigable tree.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Action;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let action = Action::<()>::new("my_action")?
+    ///         .insert_child(Action::new("my_child")?)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Errors occur if attempting to insert a child Action with a blank (empty)
+    /// keyword. Will also error if a collision occurs when attempting to insert.
+    pub fn insert_child(mut self, action: Action<T>) -> error::Result<Self> {
+        if action.keyword.is_empty() {
+            return Err(Error::new("Action must have a non-empty keyword."));
+        }
+
+        if self.children.contains_key(&action.keyword) {
+            return Err(Error::new(&format!(
+                "Action '{}' already contains a child Action '{}'.",
+                self.keyword, &action.keyword
+            )));
+        }
+
+        self.children.insert(action.keyword.clone(), action);
+        Ok(self)
+    }
+
+    /// Insert a Group into the Action.
+    ///
+    /// Insert a Group onto the Action object, declaring a relationship among
+    /// the titles it names: mutual exclusion (`multiple(false)`), a
+    /// requirement that at least one is supplied (`required(true)`), or
+    /// both. Groups are checked against the collected Fields and Flags during
+    /// `Cherry::parse`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Field, Group};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let action = Action::<()>::new("my_action")?
+    ///         .insert_field(Field::new("json")?)?
+    ///         .insert_field(Field::new("yaml")?)?
+    ///         .insert_group(
+    ///             Group::new("format")?
+    ///                 .args(&["json", "yaml"])
+    ///                 .multiple(false),
+    ///         )?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Errors occur if attempting to insert a Group that names no Fields or
+    /// Flags, or that names a title not already registered as a Field or
+    /// Flag on this Action.
+    pub fn insert_group(mut self, group: Group) -> error::Result<Self> {
+        if group.args.is_empty() {
+            return Err(Error::new(&format!(
+                "Group '{}' must reference at least one Field or Flag.",
+                group.title
+            )));
+        }
+
+        for arg in &group.args {
+            if !self.fields.contains_key(arg) && !self.flags.contains_key(arg) {
+                return Err(Error::new(&format!(
+                    "Group '{}' references unknown Field or Flag '{}'.",
+                    group.title, arg
+                )));
+            }
+        }
+
+        self.groups.push(group);
+        Ok(self)
+    }
+
+    /// Declare a prerequisite Action.
+    ///
+    /// Registers `keyword` as a prerequisite that `Cherry::dispatch` must run
+    /// before this Action. Prerequisites are resolved against the Actions
+    /// registered directly on the same Cherry instance, and run in
+    /// dependency order ahead of this Action itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Action;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let action = Action::<()>::new("deploy")?.requires("build");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn requires(mut self, keyword: &str) -> Self {
+        self.requires.push(String::from(keyword));
+        self
+    }
+
+    /// Get the prerequisite keywords.
+    ///
+    /// Retrieve the keywords registered via `requires`, in declaration order.
+    pub(crate) fn prerequisites(&self) -> &[String] {
+        &self.requires
+    }
+
+    /// Get the description.
+    ///
+    /// Retrieve the description set on this Action, if any.
+    pub(crate) fn description_str(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Get a child Action.
+    ///
+    /// Retrieve a child Action by keyword, if one is registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Action;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let action = Action::<()>::new("my_action")?
+    ///         .insert_child(Action::new("my_child")?)?;
+    ///     assert!(action.get_child("my_child").is_some());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_child(&self, keyword: &str) -> Option<&Action<T>> {
+        self.children.get(keyword)
+    }
+
+    /// Collect the Action's Fields, keyed only once by title.
+    ///
+    /// The internal `fields` map stores each Field twice: once under its title
+    /// and once under its short tag (if any). This returns each Field exactly
+    /// once, ordered by title, for use by help and completion generation.
+    fn ordered_fields(&self) -> Vec<&Field> {
+        let mut fields: Vec<&Field> = self
+            .fields
+            .iter()
+            .filter(|(key, field)| *key == &field.title)
+            .map(|(_, field)| field)
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    /// Collect the Action's Flags, keyed only once by title.
+    ///
+    /// The internal `flags` map stores each Flag twice: once under its title
+    /// and once under its short tag (if any). This returns each Flag exactly
+    /// once, ordered by title, for use by help and completion generation.
+    fn ordered_flags(&self) -> Vec<&Flag> {
+        let mut flags: Vec<&Flag> = self
+            .flags
+            .iter()
+            .filter(|(key, flag)| *key == &flag.title)
+            .map(|(_, flag)| flag)
+            .collect();
+        flags.sort();
+        flags
+    }
+
+    /// Collect the Action's child Actions, ordered by keyword.
+    fn ordered_children(&self) -> Vec<&Action<T>> {
+        let mut children: Vec<&Action<T>> = self.children.values().collect();
+        children.sort();
+        children
+    }
+
+    /// Render the help text for this Action or a descendant.
+    ///
+    /// Walks `path` through this Action's children for as long as a matching
+    /// keyword is found, then renders the help text for the deepest Action
+    /// reached. `ancestors` carries the keyword chain already walked to reach
+    /// this Action, so the USAGE line reflects the full invocation path.
+    pub(crate) fn help(&self, ancestors: &[&str], path: &[&str]) -> String {
+        match path.split_first() {
+            Some((first, rest)) if self.children.contains_key(*first) => {
+                let mut next_ancestors = ancestors.to_vec();
+                next_ancestors.push(&self.keyword);
+                self.children[*first].help(&next_ancestors, rest)
+            }
+            _ => {
+                let mut full_path = ancestors.to_vec();
+                full_path.push(&self.keyword);
+                self.render_help(&full_path)
+            }
+        }
+    }
+
+    /// Format this Action's usage block.
+    ///
+    /// Produces a clap-style `USAGE:` line followed by sections for child
+    /// Actions, Arguments, Fields and Flags, pulling descriptions from the
+    /// data already stored on this Action, and a trailing line pointing the
+    /// reader at `--help`.
+    fn render_help(&self, path: &[&str]) -> String {
+        let mut usage = format!("USAGE:\n    {}", path.join(" "));
+        if !self.children.is_empty() {
+            usage.push_str(" [ACTION]");
+        }
+        if !self.fields.is_empty() || !self.flags.is_empty() {
+            usage.push_str(" [OPTIONS]");
+        }
+        for argument in &self.arguments {
+            usage.push_str(&format!(" <{}>", argument.title));
+        }
+        usage.push('\n');
+
+        let mut text = usage;
+
+        if let Some(description) = &self.description {
+            text.push_str(&format!("\n{}\n", description));
+        }
+
+        let children = self.ordered_children();
+        if !children.is_empty() {
+            text.push_str("\nACTIONS:\n");
+            for child in children {
+                text.push_str(&format!(
+                    "    {:<20}{}\n",
+                    child.keyword,
+                    child.description.as_deref().unwrap_or("")
+                ));
+            }
+        }
+
+        if !self.arguments.is_empty() {
+            text.push_str("\nARGUMENTS:\n");
+            for argument in &self.arguments {
+                text.push_str(&format!(
+                    "    {:<20}{}{}\n",
+                    format!("<{}>", argument.title),
+                    argument.description.as_deref().unwrap_or(""),
+                    possible_values_suffix(&argument.possible_values)
+                ));
+                text.push_str(&possible_value_descriptions(&argument.possible_values));
+            }
+        }
+
+        let fields = self.ordered_fields();
+        if !fields.is_empty() {
+            text.push_str("\nFIELDS:\n");
+            for field in fields {
+                let flag = field.short.map_or_else(
+                    || format!("--{} <VALUE>", field.title),
+                    |short| format!("-{short}, --{} <VALUE>", field.title),
+                );
+                let default = field
+                    .default
+                    .as_ref()
+                    .map_or_else(String::new, |value| format!(" (default: {value})"));
+                text.push_str(&format!(
+                    "    {:<24}{}{}{}\n",
+                    flag,
+                    field.description.as_deref().unwrap_or(""),
+                    possible_values_suffix(&field.possible_values),
+                    default
+                ));
+                text.push_str(&possible_value_descriptions(&field.possible_values));
+            }
+        }
+
+        let flags = self.ordered_flags();
+        if !flags.is_empty() {
+            text.push_str("\nFLAGS:\n");
+            for flag in flags {
+                let tag = flag.short.map_or_else(
+                    || format!("--{}", flag.title),
+                    |short| format!("-{short}, --{}", flag.title),
+                );
+                text.push_str(&format!(
+                    "    {:<24}{}\n",
+                    tag,
+                    flag.description.as_deref().unwrap_or("")
+                ));
+            }
+        }
+
+        text.push_str("\nFor more information try '--help'.\n");
+
+        text
+    }
+
+    /// Collect this Action's own completion words.
+    ///
+    /// Returns this Action's child Action keywords plus the long (and, where
+    /// set, short) names of its Fields and Flags, in the order they should be
+    /// offered to a shell's completion engine.
+    pub(crate) fn completion_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = self
+            .ordered_children()
+            .iter()
+            .map(|child| child.keyword.clone())
+            .collect();
+
+        for field in self.ordered_fields() {
+            words.push(format!("--{}", field.title));
+            if let Some(short) = field.short {
+                words.push(format!("-{short}"));
+            }
+        }
+
+        for flag in self.ordered_flags() {
+            words.push(format!("--{}", flag.title));
+            if let Some(short) = flag.short {
+                words.push(format!("-{short}"));
+            }
+        }
+
+        words
+    }
+
+    /// Compute the deepest COMP_WORDS index completable under this Action.
+    ///
+    /// `depth` is the COMP_WORDS index at which this Action's own
+    /// completion_words apply. Returns `depth` itself if this Action has no
+    /// children, or the deepest index reachable through its child Actions
+    /// otherwise.
+    pub(crate) fn max_completion_depth(&self, depth: usize) -> usize {
+        self.children
+            .values()
+            .map(|child| child.max_completion_depth(depth + 1))
+            .max()
+            .unwrap_or(depth)
+    }
+
+    /// Render the nested `case "${COMP_WORDS[n]}"` chain for bash completion.
+    ///
+    /// `depth` is the COMP_WORDS index just past this Action's own keyword,
+    /// where its completion_words apply; once it reaches `target` (the word
+    /// position currently being completed), they are emitted. Otherwise a
+    /// nested case over `${COMP_WORDS[depth]}` (the child keyword) recurses
+    /// into each child Action, `indent` levels of four spaces deep.
+    pub(crate) fn bash_case_chain(&self, depth: usize, target: usize, indent: usize) -> String {
+        let pad = "    ".repeat(indent);
+
+        if depth == target {
+            return format!("{pad}opts=\"{}\"\n", self.completion_words().join(" "));
+        }
+
+        let mut text = format!("{pad}case \"${{COMP_WORDS[{depth}]}}\" in\n");
+        for child in self.ordered_children() {
+            text.push_str(&format!("{pad}    {})\n", child.keyword));
+            text.push_str(&child.bash_case_chain(depth + 1, target, indent + 2));
+            text.push_str(&format!("{pad}        ;;\n"));
+        }
+        text.push_str(&format!("{pad}esac\n"));
+        text
+    }
+
+    /// Render this Action's zsh `_arguments` option specs.
+    ///
+    /// Each Field becomes a spec accepting a following value, and each Flag a
+    /// standalone switch spec, both carrying their `description` (if any) so
+    /// zsh can show help text alongside the completion menu.
+    pub(crate) fn zsh_option_specs(&self) -> Vec<String> {
+        let mut specs = Vec::new();
+
+        for field in self.ordered_fields() {
+            let description = field.description.as_deref().unwrap_or("");
+            let tag = match field.short {
+                Some(short) => format!("'(-{short} --{0})'{{-{short},--{0}}}", field.title),
+                None => format!("'--{}'", field.title),
+            };
+            let values = zsh_value_choices(&field.possible_values);
+            specs.push(format!("{tag}'[{description}]:value:{values}'"));
+        }
+
+        for flag in self.ordered_flags() {
+            let description = flag.description.as_deref().unwrap_or("");
+            let tag = match flag.short {
+                Some(short) => format!("'(-{short} --{0})'{{-{short},--{0}}}", flag.title),
+                None => format!("'--{}'", flag.title),
+            };
+            specs.push(format!("{tag}'[{description}]'"));
+        }
+
+        specs
+    }
+
+    /// Render this Action's zsh completion function, recursing into children.
+    ///
+    /// `function` is the name this Action's function should be defined
+    /// under; each child Action's function is named `{function}_{keyword}`.
+    /// An Action with children dispatches into them through a `_describe`d
+    /// subcommand list; a leaf Action just runs its own `_arguments` spec.
+    pub(crate) fn zsh_function(&self, function: &str) -> String {
+        let specs = self.zsh_option_specs();
+        let children = self.ordered_children();
+
+        let mut text = format!("{function}() {{\n");
+        if children.is_empty() {
+            if specs.is_empty() {
+                text.push_str("    return 0\n");
+            } else {
+                text.push_str(&format!("    _arguments \\\n        {}\n", specs.join(" \\\n        ")));
+            }
+        } else {
+            let subcommands: Vec<String> = children
+                .iter()
+                .map(|child| format!("'{}:{}'", child.keyword, child.description.as_deref().unwrap_or("")))
+                .collect();
+            let mut arguments = specs.clone();
+            arguments.push(String::from("'1: :->command'"));
+            arguments.push(String::from("'*::arg:->args'"));
+
+            text.push_str(&format!(
+                "    local -a subcommands\n    subcommands=({})\n\n    \
+                 _arguments -C \\\n        {}\n\n    \
+                 case $state in\n        command)\n            _describe 'command' subcommands\n            ;;\n        \
+                 args)\n            case $words[1] in\n",
+                subcommands.join(" "),
+                arguments.join(" \\\n        "),
+            ));
+            for child in &children {
+                text.push_str(&format!(
+                    "                {})\n                    {function}_{}\n                    ;;\n",
+                    child.keyword, child.keyword
+                ));
+            }
+            text.push_str("            esac\n            ;;\n    esac\n");
+        }
+        text.push_str("}\n");
+
+        for child in children {
+            text.push('\n');
+            text.push_str(&child.zsh_function(&format!("{function}_{}", child.keyword)));
+        }
+
+        text
+    }
+
+    /// Append this Action's fish `complete -c` lines, recursing into children.
+    ///
+    /// `ancestors` is the chain of keywords leading to this Action. Each
+    /// emitted line is conditioned on `__fish_seen_subcommand_from` for every
+    /// keyword in the chain (including this Action's own), so its Fields,
+    /// Flags and child Actions are only offered once the full path has
+    /// already been typed.
+    pub(crate) fn fish_complete_lines(&self, bin_name: &str, ancestors: &[&str], text: &mut String) {
+        let mut path: Vec<&str> = ancestors.to_vec();
+        path.push(&self.keyword);
+        let seen = path
+            .iter()
+            .map(|keyword| format!("__fish_seen_subcommand_from {keyword}"))
+            .collect::<Vec<_>>()
+            .join("; and ");
+
+        for child in self.ordered_children() {
+            text.push_str(&format!(
+                "complete -c {bin_name} -n '{seen}' -a '{}' -d '{}'\n",
+                child.keyword,
+                child.description.as_deref().unwrap_or("")
+            ));
+        }
+
+        for field in self.ordered_fields() {
+            let description = field.description.as_deref().unwrap_or("");
+            let values = field.possible_values.as_ref().map_or_else(String::new, |values| {
+                let names: Vec<&str> = values.iter().map(|(value, _)| value.as_str()).collect();
+                format!(" -a '{}'", names.join(" "))
+            });
+            text.push_str(&format!(
+                "complete -c {bin_name} -n '{seen}' -l {} -d '{description}' -r{values}\n",
+                field.title
+            ));
+            if let Some(short) = field.short {
+                text.push_str(&format!(
+                    "complete -c {bin_name} -n '{seen}' -s {short} -d '{description}' -r{values}\n"
+                ));
+            }
+        }
+
+        for flag in self.ordered_flags() {
+            let description = flag.description.as_deref().unwrap_or("");
+            text.push_str(&format!(
+                "complete -c {bin_name} -n '{seen}' -l {} -d '{description}'\n",
+                flag.title
+            ));
+            if let Some(short) = flag.short {
+                text.push_str(&format!(
+                    "complete -c {bin_name} -n '{seen}' -s {short} -d '{description}'\n"
+                ));
+            }
+        }
+
+        for child in self.ordered_children() {
+            child.fish_complete_lines(bin_name, &path, text);
+        }
+    }
+
+    /// Render the nested PowerShell token-match chain for this Action.
+    ///
+    /// Mirrors `bash_case_chain`: `depth` is the token index (the binary
+    /// name being token 0) just past this Action's own keyword, where its
+    /// completion_words apply; once it reaches `target` (the token currently
+    /// being completed), they are emitted as the candidate array. Otherwise
+    /// a nested `switch` over `$tokens[depth]` (the child keyword) recurses
+    /// into each child Action.
+    pub(crate) fn powershell_case_chain(&self, depth: usize, target: usize, indent: usize) -> String {
+        let pad = "    ".repeat(indent);
+
+        if depth == target {
+            let words: Vec<String> = self
+                .completion_words()
+                .iter()
+                .map(|word| format!("'{word}'"))
+                .collect();
+            return format!("{pad}@({})\n", words.join(", "));
+        }
+
+        let mut text = format!("{pad}switch ($tokens[{depth}]) {{\n");
+        for child in self.ordered_children() {
+            text.push_str(&format!("{pad}    '{}' {{\n", child.keyword));
+            text.push_str(&child.powershell_case_chain(depth + 1, target, indent + 2));
+            text.push_str(&format!("{pad}    }}\n"));
+        }
+        text.push_str(&format!("{pad}    default {{ @() }}\n"));
+        text.push_str(&format!("{pad}}}\n"));
+        text
+    }
+
     /// Run this Action.
     ///
     /// Execute this Action's callback using the provided Request. The Request
@@ -349,7 +982,7 @@ impl<T> Action<T> {
 
         self.then
             .as_ref()
-            .map_or_else(|| Err(Error::new("Todo: Help.")), |then| Ok(then(request)))
+            .map_or_else(|| Err(Error::new(&self.help(&[], &[]))), |then| Ok(then(request)))
     }
 
     /// Update the then callback on the Action.
@@ -389,6 +1022,53 @@ impl<T> Action<T> {
         self.then = Some(Box::new(then));
         self
     }
+
+    /// Assert the Action is internally consistent.
+    ///
+    /// Checks for the one class of programmer error `insert_field`,
+    /// `insert_flag`, and `insert_argument` cannot catch as it happens:
+    /// duplicate titles/short tags and a non-`multiple` Argument following a
+    /// `multiple` one are already rejected at insertion time, but a Field or
+    /// Flag's `conflicts_with`/`requires`/`required_unless` is just a bare
+    /// name, recorded before the Action exists to check it against. This
+    /// walks every such reference once the Action is complete and panics,
+    /// naming the offending Field/Flag and the unknown name, if any of them
+    /// do not resolve to a declared Field or Flag. Run automatically by
+    /// `Cherry::insert` in debug builds; callers may also invoke it directly.
+    ///
+    /// # Panics
+    /// Panics if a Field or Flag's `conflicts_with`, `requires`, or
+    /// `required_unless` names a Field or Flag the Action does not declare.
+    pub fn assert(&self) {
+        let names: HashSet<&str> = self
+            .ordered_fields()
+            .into_iter()
+            .map(|field| field.title.as_str())
+            .chain(self.ordered_flags().into_iter().map(|flag| flag.title.as_str()))
+            .collect();
+
+        for field in self.ordered_fields() {
+            for reference in field.conflicts.iter().chain(&field.requires).chain(&field.required_unless) {
+                if !names.contains(reference.as_str()) {
+                    panic!(
+                        "Action '{}': Field '{}' references unknown name '{}'.",
+                        self.keyword, field.title, reference
+                    );
+                }
+            }
+        }
+
+        for flag in self.ordered_flags() {
+            for reference in flag.conflicts.iter().chain(&flag.requires).chain(&flag.required_unless) {
+                if !names.contains(reference.as_str()) {
+                    panic!(
+                        "Action '{}': Flag '{}' references unknown name '{}'.",
+                        self.keyword, flag.title, reference
+                    );
+                }
+            }
+        }
+    }
 }
 
 impl<T> Debug for Action<T> {
@@ -419,9 +1099,19 @@ impl<T> Debug for Action<T> {
                     arguments: {:?}, \
                     fields: {:?}, \
                     flags: {:?}, \
+                    children: {:?}, \
+                    groups: {:?}, \
+                    requires: {:?}, \
                     then: Some(fn(Request<T>) -> T) \
                 }}",
-                self.keyword, self.description, self.arguments, self.fields, self.flags
+                self.keyword,
+                self.description,
+                self.arguments,
+                self.fields,
+                self.flags,
+                self.children,
+                self.groups,
+                self.requires
             ),
             None => write!(
                 f,
@@ -431,9 +1121,19 @@ impl<T> Debug for Action<T> {
                     arguments: {:?}, \
                     fields: {:?}, \
                     flags: {:?}, \
+                    children: {:?}, \
+                    groups: {:?}, \
+                    requires: {:?}, \
                     then: None \
                 }}",
-                self.keyword, self.description, self.arguments, self.fields, self.flags
+                self.keyword,
+                self.description,
+                self.arguments,
+                self.fields,
+                self.flags,
+                self.children,
+                self.groups,
+                self.requires
             ),
         }
     }
@@ -504,106 +1204,304 @@ impl<T> PartialOrd for Action<T> {
     }
 }
 
-/// Argument.
+/// Group.
 ///
-/// Arguments are the initial separated values parsed by the Cherry instance.
-/// Arguments are consumed immediately after an Action is selected. If
-/// Arguments have a filter method, this filter is run against the provided
-/// value to determine if the provided value is valid, and therefore if the
-/// command provided to the Cherry instance was valid.
+/// A Group declares a relationship among a set of a single Action's Field
+/// and Flag titles, checked after a command has been fully parsed. By
+/// default a Group neither restricts how many of its args may be used
+/// together nor requires any of them; `multiple(false)` makes its args
+/// mutually exclusive, and `required(true)` requires at least one of them.
 ///
 /// # Example
 /// ```rust
-/// use cherry::{Action, Argument, Cherry};
+/// use cherry::{Action, Field, Group};
 ///
 /// fn main() -> cherry::Result<()> {
-///     let cherry = Cherry::new()
-///         .insert(
-///             Action::new("my_action")?
-///                 .insert_argument(
-///                     Argument::new("greeting")?
-///                         .description("The greeting to display, must be hello.")
-///                         .filter(|value| { value == "hello" })
-///                 )?
-///                 .then(|result| -> Option<String> { result.get_argument(0).cloned() })
+///     let action = Action::<()>::new("my_action")?
+///         .insert_field(Field::new("json")?)?
+///         .insert_field(Field::new("yaml")?)?
+///         .insert_group(
+///             Group::new("format")?
+///                 .args(&["json", "yaml"])
+///                 .required(true)
+///                 .multiple(false),
 ///         )?;
-///
-///      // Will provide value "Hello"
-///      cherry.parse_str("my_action hello");
-///      Ok(())
+///     Ok(())
 /// }
 /// ```
-#[derive(Clone)]
-pub struct Argument {
-    /// The Argument title for use in help text.
-    pub title: String,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Group {
+    /// The Group's title, used to identify it in error messages.
+    title: String,
 
-    /// The Argument description for use in help text.
-    description: Option<String>,
+    /// The Field/Flag titles this Group governs.
+    args: Vec<String>,
 
-    /// The filter to determine if the provided value is valid.
-    filter: Option<Rc<dyn Fn(&str) -> bool>>,
+    /// Whether at least one of this Group's args must be supplied.
+    required: bool,
+
+    /// Whether more than one of this Group's args may be supplied together.
+    multiple: bool,
 }
 
-impl Argument {
-    /// Create a new Argument.
+impl Group {
+    /// Create a new Group.
     ///
-    /// Create a new Argument instance.
+    /// Create a new Group instance.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::Argument;
+    /// use cherry::Group;
     ///
     /// fn main() -> cherry::Result<()> {
-    ///     let argument = Argument::new("name")?;
+    ///     let group = Group::new("format")?;
     ///     Ok(())
     /// }
     /// ```
     ///
     /// # Error
-    /// Will error when a blank (empty) title is provided. Arguments must have a
+    /// Will error when a blank (empty) title is provided. Groups must have a
     /// non-empty title assigned to them.
     pub fn new(title: &str) -> error::Result<Self> {
         if title.is_empty() {
-            return Err(Error::new("Argument must have a non-empty title."));
+            return Err(Error::new("Group must have a non-empty title."));
         }
 
         Ok(Self {
             title: String::from(title),
-            description: None,
-            filter: None,
+            args: Vec::new(),
+            required: false,
+            multiple: true,
         })
     }
 
-    /// Update the description.
+    /// Set the Field/Flag titles this Group governs.
     ///
-    /// The description of the Argument is used by the help text to assist users of
-    /// the application to understand it. A good description text allows users to
-    /// effectively use the application.
+    /// Replaces any previously set args with `args`.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::Argument;
+    /// use cherry::Group;
     ///
     /// fn main() -> cherry::Result<()> {
-    ///     let argument = Argument::new("my_argument")?
-    ///        .description("The argument description");
+    ///     let group = Group::new("format")?.args(&["json", "yaml"]);
     ///     Ok(())
     /// }
     /// ```
-    pub fn description(mut self, description: &str) -> Self {
-        self.description = Some(String::from(description));
+    pub fn args(mut self, args: &[&str]) -> Self {
+        self.args = args.iter().map(|arg| String::from(*arg)).collect();
         self
     }
 
-    /// Update the filter callback on the Argument.
-    ///
-    /// The filter callback of the Argument is the method or closure that is called
-    /// when this Argument is parsed from the input to determine if the input is
-    /// valid.
+    /// Set whether at least one of this Group's args is required.
     ///
     /// # Example
-    /// ## Using a method
+    /// ```rust
+    /// use cherry::Group;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let group = Group::new("format")?.required(true);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Set whether more than one of this Group's args may be used together.
+    ///
+    /// Passing `false` makes this Group's args mutually exclusive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Group;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let group = Group::new("format")?.multiple(false);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+}
+
+/// FilterOutcome.
+///
+/// The return type accepted by `Argument::filter` and `Field::filter`.
+/// Implemented for `Result<(), String>`, so a validator can reject a value
+/// with a reason surfaced through `Error::InvalidValue`, for `bool`, so
+/// existing plain predicates keep compiling with no message attached, and
+/// for `error::Result<()>`, so a `validate::Validator` (whose `validate`
+/// method already returns that type) can be used as a filter directly.
+pub trait FilterOutcome {
+    /// Convert into the canonical `Result<(), String>` filter outcome.
+    fn into_filter_result(self) -> Result<(), String>;
+}
+
+impl FilterOutcome for Result<(), String> {
+    fn into_filter_result(self) -> Result<(), String> {
+        self
+    }
+}
+
+impl FilterOutcome for error::Result<()> {
+    /// An Err carries the Error's Display message, so the original failure
+    /// reason (e.g. from a `Validator`) is preserved as the filter reason.
+    fn into_filter_result(self) -> Result<(), String> {
+        self.map_err(|error| error.to_string())
+    }
+}
+
+impl FilterOutcome for bool {
+    /// A `false` result carries no reason, matching the predicate's original
+    /// `Fn(&str) -> bool` contract.
+    fn into_filter_result(self) -> Result<(), String> {
+        if self {
+            Ok(())
+        } else {
+            Err(String::new())
+        }
+    }
+}
+
+/// Argument.
+///
+/// Arguments are the initial separated values parsed by the Cherry instance.
+/// Arguments are consumed immediately after an Action is selected. If
+/// Arguments have a filter method, this filter is run against the provided
+/// value to determine if the provided value is valid, and therefore if the
+/// command provided to the Cherry instance was valid.
+///
+/// # Example
+/// ```rust
+/// use cherry::{Action, Argument, Cherry};
+///
+/// fn main() -> cherry::Result<()> {
+///     let cherry = Cherry::new()
+///         .insert(
+///             Action::new("my_action")?
+///                 .insert_argument(
+///                     Argument::new("greeting")?
+///                         .description("The greeting to display, must be hello.")
+///                         .filter(|value| { value == "hello" })
+///                 )?
+///                 .then(|result| -> Option<String> { result.get_argument(0).cloned() })
+///         )?;
+///
+///      // Will provide value "Hello"
+///      cherry.parse_str("my_action hello");
+///      Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Argument {
+    /// The Argument title for use in help text.
+    pub title: String,
+
+    /// The Argument description for use in help text.
+    description: Option<String>,
+
+    /// The filter to determine if the provided value is valid. Returns the
+    /// rejection reason on failure, so it can be surfaced to the user.
+    filter: Option<Rc<dyn Fn(&str) -> Result<(), String>>>,
+
+    /// The parser that converts the provided value into a typed value,
+    /// retrieved through `Request::argument_as`.
+    value_parser: Option<Rc<dyn Fn(&str) -> Result<Rc<dyn Any>, String>>>,
+
+    /// The fixed set of values this Argument accepts, each with an optional
+    /// description for use in help text.
+    possible_values: Option<Vec<(String, Option<String>)>>,
+
+    /// Whether a supplied value is matched against `possible_values`
+    /// ignoring case.
+    case_insensitive: bool,
+
+    /// Whether this Argument collects one or more trailing values rather
+    /// than exactly one. Only meaningful on the last Argument declared on
+    /// an Action.
+    multiple: bool,
+
+    /// The minimum number of values a `multiple` Argument must collect.
+    /// Defaults to `1` when unset.
+    min_values: Option<usize>,
+
+    /// The maximum number of values a `multiple` Argument may collect.
+    /// Defaults to unbounded when unset.
+    max_values: Option<usize>,
+}
+
+impl Argument {
+    /// Create a new Argument.
+    ///
+    /// Create a new Argument instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("name")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error when a blank (empty) title is provided. Arguments must have a
+    /// non-empty title assigned to them.
+    pub fn new(title: &str) -> error::Result<Self> {
+        if title.is_empty() {
+            return Err(Error::new("Argument must have a non-empty title."));
+        }
+
+        Ok(Self {
+            title: String::from(title),
+            description: None,
+            filter: None,
+            value_parser: None,
+            possible_values: None,
+            case_insensitive: false,
+            multiple: false,
+            min_values: None,
+            max_values: None,
+        })
+    }
+
+    /// Update the description.
+    ///
+    /// The description of the Argument is used by the help text to assist users of
+    /// the application to understand it. A good description text allows users to
+    /// effectively use the application.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("my_argument")?
+    ///        .description("The argument description");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(String::from(description));
+        self
+    }
+
+    /// Update the filter callback on the Argument.
+    ///
+    /// The filter callback of the Argument is the method or closure that is called
+    /// when this Argument is parsed from the input to determine if the input is
+    /// valid. Returning `Err(reason)` rejects the value and surfaces `reason`
+    /// through `Error::InvalidValue`; a plain `bool` predicate is also
+    /// accepted for backwards compatibility, but a `false` result carries no
+    /// reason.
+    ///
+    /// # Example
+    /// ## Using a method
     /// ```rust
     /// use cherry::{Argument};
     ///
@@ -618,21 +1516,187 @@ impl Argument {
     /// }
     /// ```
     ///
-    /// ## Using a closure
+    /// ## Using a closure with a rejection reason
     /// ```rust
     /// use cherry::Argument;
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let action = Argument::new("my_action")?
-    ///         .filter(|val: &str| -> bool {
-    ///             // Implement application logic.
-    ///             true
+    ///         .filter(|value: &str| -> Result<(), String> {
+    ///             if value.chars().all(char::is_alphanumeric) {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err(String::from("must be alphanumeric"))
+    ///             }
     ///         });
     ///     Ok(())
     /// }
     /// ```
-    pub fn filter(mut self, filter: impl Fn(&str) -> bool + 'static) -> Self {
-        self.filter = Some(Rc::new(filter));
+    pub fn filter<R: FilterOutcome>(mut self, filter: impl Fn(&str) -> R + 'static) -> Self {
+        self.filter = Some(Rc::new(move |value: &str| filter(value).into_filter_result()));
+        self
+    }
+
+    /// Update the value parser callback on the Argument.
+    ///
+    /// The value parser converts the raw string value into a typed value
+    /// `V`, retrieved later through `Request::argument_as`. Returning `Err`
+    /// from the parser aborts parsing, surfacing the message through
+    /// `Error::InvalidValue`. See the `parser` module for built-in parser
+    /// constructors covering common cases.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("count")?
+    ///         .value_parser(|value| value.parse::<u32>().map_err(|error| error.to_string()));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn value_parser<V: 'static>(
+        mut self,
+        parser: impl Fn(&str) -> Result<V, String> + 'static,
+    ) -> Self {
+        self.value_parser = Some(Rc::new(move |value: &str| {
+            parser(value).map(|value| Rc::new(value) as Rc<dyn Any>)
+        }));
+        self
+    }
+
+    /// Restrict the accepted values to a fixed set.
+    ///
+    /// Restricts the Argument to only accept one of `values`. A value
+    /// outside this set is rejected with `Error::InvalidChoice` before any
+    /// `filter` runs. Calling this replaces any values set through a
+    /// previous call to `possible_values` or `possible_value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("action")?
+    ///         .possible_values(&["add", "remove", "list"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn possible_values(mut self, values: &[&str]) -> Self {
+        self.possible_values = Some(
+            values
+                .iter()
+                .map(|value| (String::from(*value), None))
+                .collect(),
+        );
+        self
+    }
+
+    /// Add a single accepted value with a description.
+    ///
+    /// Appends `value` to the Argument's accepted set, paired with a
+    /// `description` shown alongside it in help text. Unlike
+    /// `possible_values`, repeated calls accumulate rather than replace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("action")?
+    ///         .possible_value("add", "Add an item")
+    ///         .possible_value("remove", "Remove an item");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn possible_value(mut self, value: &str, description: &str) -> Self {
+        self.possible_values
+            .get_or_insert_with(Vec::new)
+            .push((String::from(value), Some(String::from(description))));
+        self
+    }
+
+    /// Match `possible_values` ignoring case.
+    ///
+    /// Without this, a supplied value must match a `possible_values` entry
+    /// byte for byte. Has no effect unless `possible_values`/`possible_value`
+    /// is also set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("action")?
+    ///         .possible_values(&["add", "remove"])
+    ///         .case_insensitive();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Accept one or more trailing values instead of exactly one.
+    ///
+    /// Marks this Argument as variadic, so the parser greedily collects
+    /// every remaining positional token into it rather than requiring
+    /// exactly one value. Only meaningful on the last Argument declared on
+    /// an Action; `Action::insert_argument` rejects adding another Argument
+    /// after one marked `multiple`. The collected values are retrieved
+    /// through `Request::get_argument_values`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("files")?.multiple(true);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the minimum number of values a `multiple` Argument must collect.
+    ///
+    /// Defaults to `1` when unset, so a `multiple` Argument requires at
+    /// least one value unless explicitly relaxed. Has no effect on an
+    /// Argument that is not `multiple`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("files")?.multiple(true).min_values(2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn min_values(mut self, min_values: usize) -> Self {
+        self.min_values = Some(min_values);
+        self
+    }
+
+    /// Set the maximum number of values a `multiple` Argument may collect.
+    ///
+    /// Defaults to unbounded when unset. Has no effect on an Argument that
+    /// is not `multiple`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Argument;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let argument = Argument::new("files")?.multiple(true).max_values(3);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn max_values(mut self, max_values: usize) -> Self {
+        self.max_values = Some(max_values);
         self
     }
 }
@@ -656,18 +1720,19 @@ impl Debug for Argument {
     /// # Error
     /// Will error if the underlying write macro fails.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self.filter {
-            Some(_) => write!(
-                f,
-                "Argument {{ title: {:?}, description: {:?}, filter: Some(fn(&str) -> bool) }}",
-                self.title, self.description,
-            ),
-            None => write!(
-                f,
-                "Argument {{ title: {:?}, description: {:?}, filter: None }}",
-                self.title, self.description,
-            ),
-        }
+        let filter = match self.filter {
+            Some(_) => "Some(fn(&str) -> Result<(), String>)",
+            None => "None",
+        };
+        let value_parser = match self.value_parser {
+            Some(_) => "Some(fn(&str) -> Result<_, String>)",
+            None => "None",
+        };
+        write!(
+            f,
+            "Argument {{ title: {:?}, description: {:?}, filter: {filter}, value_parser: {value_parser}, possible_values: {:?}, case_insensitive: {:?}, multiple: {:?}, min_values: {:?}, max_values: {:?} }}",
+            self.title, self.description, self.possible_values, self.case_insensitive, self.multiple, self.min_values, self.max_values,
+        )
     }
 }
 
@@ -711,7 +1776,13 @@ impl PartialEq for Argument {
     /// }
     /// ```
     fn eq(&self, other: &Self) -> bool {
-        self.title == other.title && self.description == other.description
+        self.title == other.title
+            && self.description == other.description
+            && self.possible_values == other.possible_values
+            && self.case_insensitive == other.case_insensitive
+            && self.multiple == other.multiple
+            && self.min_values == other.min_values
+            && self.max_values == other.max_values
     }
 }
 
@@ -736,12 +1807,44 @@ impl PartialOrd for Argument {
     }
 }
 
+/// FieldType.
+///
+/// Declares the type a Field's raw value is parsed into during `Cherry::parse`.
+/// Defaults to `FieldType::String`, which stores the raw value verbatim.
+///
+/// # Example
+/// ```rust
+/// use cherry::{Field, FieldType};
+///
+/// fn main() -> cherry::Result<()> {
+///     let field = Field::new("port")?
+///         .kind(FieldType::Integer);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldType {
+    /// Store the raw value verbatim, performing no conversion.
+    String,
+    /// Parse the value as a signed 64 bit integer.
+    Integer,
+    /// Parse the value as a 64 bit floating point number.
+    Float,
+    /// Parse the value as a boolean, accepting only `true` or `false`.
+    Bool,
+}
+
 /// Field.
 ///
 /// Fields are optional Arguments. Flags are parsed by the Cherry instance by
 /// using the full specifier `--title` or the short version `-t`, followed by
 /// the Field value. If a Field is accidentally specified multiple times during
-/// parsing of a command, the final value will remain..
+/// parsing of a command, the final value will remain, unless the Field is
+/// marked `multiple`, in which case every occurrence is collected in order
+/// and retrieved through `Request::get_fields`. A Field's raw value is
+/// parsed into its declared `kind` (a `FieldType`, `String` by default), and
+/// a Field can be marked `required`, so `Request::validate` fails unless a
+/// value or default was supplied.
 ///
 /// # Example
 /// ```rust
@@ -782,8 +1885,51 @@ pub struct Field {
     /// The Field default value.
     default: Option<String>,
 
-    /// The filter to determine if the provided value is valid.
-    filter: Option<Rc<dyn Fn(&str) -> bool>>,
+    /// The filter to determine if the provided value is valid. Returns the
+    /// rejection reason on failure, so it can be surfaced to the user.
+    filter: Option<Rc<dyn Fn(&str) -> Result<(), String>>>,
+
+    /// The parser that converts the provided value into a typed value,
+    /// retrieved through `Request::field_parsed`.
+    value_parser: Option<Rc<dyn Fn(&str) -> Result<Rc<dyn Any>, String>>>,
+
+    /// The fixed set of values this Field accepts, each with an optional
+    /// description for use in help text.
+    possible_values: Option<Vec<(String, Option<String>)>>,
+
+    /// Whether a supplied value is matched against `possible_values`
+    /// ignoring case.
+    case_insensitive: bool,
+
+    /// The type this Field's value is parsed into during `Cherry::parse`.
+    kind: FieldType,
+
+    /// Whether this Field must be given a value or a default.
+    required: bool,
+
+    /// Whether this Field collects every occurrence given during parsing
+    /// into a list, rather than the last one overwriting the rest.
+    multiple: bool,
+
+    /// The minimum number of occurrences a `required`, `multiple` Field must
+    /// collect. Defaults to `1` when unset.
+    min_values: Option<usize>,
+
+    /// The maximum number of occurrences a `multiple` Field may collect.
+    /// Defaults to unbounded when unset.
+    max_values: Option<usize>,
+
+    /// Sibling Field/Flag titles that must not be present alongside this
+    /// one.
+    conflicts: Vec<String>,
+
+    /// Sibling Field/Flag titles that must also be present whenever this one
+    /// is.
+    requires: Vec<String>,
+
+    /// A sibling Field/Flag title whose presence waives this Field's
+    /// `required` check.
+    required_unless: Option<String>,
 }
 
 impl Field {
@@ -815,6 +1961,17 @@ impl Field {
             short: None,
             default: None,
             filter: None,
+            value_parser: None,
+            possible_values: None,
+            case_insensitive: false,
+            kind: FieldType::String,
+            required: false,
+            multiple: false,
+            min_values: None,
+            max_values: None,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            required_unless: None,
         })
     }
 
@@ -863,7 +2020,10 @@ impl Field {
     ///
     /// The filter callback of the Field is the method or closure that is called
     /// when this Field is parsed from the input to determine if the input is
-    /// valid.
+    /// valid. Returning `Err(reason)` rejects the value and surfaces `reason`
+    /// through `Error::InvalidValue`; a plain `bool` predicate is also
+    /// accepted for backwards compatibility, but a `false` result carries no
+    /// reason.
     ///
     /// # Example
     /// ## Using a method
@@ -881,21 +2041,229 @@ impl Field {
     /// }
     /// ```
     ///
-    /// ## Using a closure
+    /// ## Using a closure with a rejection reason
     /// ```rust
     /// use cherry::Field;
     ///
     /// fn main() -> cherry::Result<()> {
-    ///     let field = Field::new("my_field")?
-    ///         .filter(|val: &str| -> bool {
-    ///             // Implement application logic.
-    ///             true
+    ///     let field = Field::new("username")?
+    ///         .filter(|value: &str| -> Result<(), String> {
+    ///             if value.chars().all(char::is_alphanumeric) {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err(String::from("must be alphanumeric"))
+    ///             }
     ///         });
     ///     Ok(())
     /// }
     /// ```
-    pub fn filter(mut self, filter: impl Fn(&str) -> bool + 'static) -> Self {
-        self.filter = Some(Rc::new(filter));
+    pub fn filter<R: FilterOutcome>(mut self, filter: impl Fn(&str) -> R + 'static) -> Self {
+        self.filter = Some(Rc::new(move |value: &str| filter(value).into_filter_result()));
+        self
+    }
+
+    /// Update the value parser callback on the Field.
+    ///
+    /// The value parser converts the raw string value into a typed value
+    /// `V`, retrieved later through `Request::field_parsed`. Returning `Err`
+    /// from the parser aborts parsing, surfacing the message through
+    /// `Error::InvalidValue`. See the `parser` module for built-in parser
+    /// constructors covering common cases.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("port")?
+    ///         .value_parser(|value| value.parse::<u16>().map_err(|error| error.to_string()));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn value_parser<V: 'static>(
+        mut self,
+        parser: impl Fn(&str) -> Result<V, String> + 'static,
+    ) -> Self {
+        self.value_parser = Some(Rc::new(move |value: &str| {
+            parser(value).map(|value| Rc::new(value) as Rc<dyn Any>)
+        }));
+        self
+    }
+
+    /// Restrict the accepted values to a fixed set.
+    ///
+    /// Restricts the Field to only accept one of `values`. A value outside
+    /// this set is rejected with `Error::InvalidChoice` before any `filter`
+    /// runs. Calling this replaces any values set through a previous call to
+    /// `possible_values` or `possible_value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("mode")?
+    ///         .possible_values(&["add", "remove", "list"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn possible_values(mut self, values: &[&str]) -> Self {
+        self.possible_values = Some(
+            values
+                .iter()
+                .map(|value| (String::from(*value), None))
+                .collect(),
+        );
+        self
+    }
+
+    /// Add a single accepted value with a description.
+    ///
+    /// Appends `value` to the Field's accepted set, paired with a
+    /// `description` shown alongside it in help text. Unlike
+    /// `possible_values`, repeated calls accumulate rather than replace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("mode")?
+    ///         .possible_value("add", "Add an item")
+    ///         .possible_value("remove", "Remove an item");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn possible_value(mut self, value: &str, description: &str) -> Self {
+        self.possible_values
+            .get_or_insert_with(Vec::new)
+            .push((String::from(value), Some(String::from(description))));
+        self
+    }
+
+    /// Match `possible_values` ignoring case.
+    ///
+    /// Without this, a supplied value must match a `possible_values` entry
+    /// byte for byte. Has no effect unless `possible_values`/`possible_value`
+    /// is also set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("mode")?
+    ///         .possible_values(&["add", "remove"])
+    ///         .case_insensitive();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Set the declared type of the Field.
+    ///
+    /// The type of the Field determines how its raw value is parsed during
+    /// `Cherry::parse`. Parsing happens before the filter, if any, is run
+    /// on the raw value. Defaults to `FieldType::String`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Field, FieldType};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("port")?
+    ///        .kind(FieldType::Integer);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn kind(mut self, kind: FieldType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Mark the Field as required.
+    ///
+    /// A required Field without a default must be given a value during
+    /// parsing, or `Request::validate` will return `Err`. See
+    /// `required_unless` to waive this when a sibling Field/Flag is present
+    /// instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("username")?
+    ///        .required();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Mark the Field as required, unless a sibling is present.
+    ///
+    /// Behaves as `required`, except `Request::validate` waives the
+    /// requirement when the Field/Flag titled `title` is itself present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("username")?
+    ///        .required_unless("config");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn required_unless(mut self, title: &str) -> Self {
+        self.required = true;
+        self.required_unless = Some(String::from(title));
+        self
+    }
+
+    /// Declare a sibling Field/Flag this Field conflicts with.
+    ///
+    /// Repeated calls accumulate rather than replace. `Request::validate`
+    /// returns `Err` if this Field and `title` are both present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("json")?.conflicts_with("yaml");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn conflicts_with(mut self, title: &str) -> Self {
+        self.conflicts.push(String::from(title));
+        self
+    }
+
+    /// Declare sibling Fields/Flags this Field requires.
+    ///
+    /// `Request::validate` returns `Err` for each of `titles` missing
+    /// whenever this Field is present. Repeated calls accumulate rather than
+    /// replace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("username")?.requires(&["password"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn requires(mut self, titles: &[&str]) -> Self {
+        self.requires
+            .extend(titles.iter().map(|title| String::from(*title)));
         self
     }
 
@@ -918,6 +2286,70 @@ impl Field {
         self.short = Some(short);
         self
     }
+
+    /// Collect every occurrence of this Field into a list instead of
+    /// overwriting.
+    ///
+    /// Marks this Field as repeatable, so a command that supplies
+    /// `--title a --title b` collects both values in order rather than the
+    /// last one overwriting the rest. The collected raw values are
+    /// retrieved through `Request::get_fields`; `Request::get_field` still
+    /// reflects the most recently parsed occurrence.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("tag")?.multiple(true);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the minimum number of occurrences a `required`, `multiple` Field
+    /// must collect.
+    ///
+    /// Defaults to `1` when unset. Has no effect on a Field that is not
+    /// `multiple`, and is only enforced by `Request::validate` when the
+    /// Field is also `required`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("tag")?.multiple(true).required().min_values(2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn min_values(mut self, min_values: usize) -> Self {
+        self.min_values = Some(min_values);
+        self
+    }
+
+    /// Set the maximum number of occurrences a `multiple` Field may collect.
+    ///
+    /// Defaults to unbounded when unset. Has no effect on a Field that is
+    /// not `multiple`. A command supplying more than `max_values`
+    /// occurrences is rejected at parse time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Field;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let field = Field::new("tag")?.multiple(true).max_values(3);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn max_values(mut self, max_values: usize) -> Self {
+        self.max_values = Some(max_values);
+        self
+    }
 }
 
 impl Debug for Field {
@@ -939,30 +2371,49 @@ impl Debug for Field {
     /// # Error
     /// Will error if the underlying write macro fails.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self.filter {
-            Some(_) => write!(
-                f,
-                "Field {{ \
-                    title: {:?}, \
-                    description: {:?}, \
-                    short: {:?}, \
-                    default: {:?}, \
-                    filter: Some(fn(&str) -> bool) \
-                }}",
-                self.title, self.description, self.short, self.default,
-            ),
-            None => write!(
-                f,
-                "Field {{ \
-                    title: {:?}, \
-                    description: {:?}, \
-                    short: {:?}, \
-                    default: {:?}, \
-                    filter: None \
-                }}",
-                self.title, self.description, self.short, self.default,
-            ),
-        }
+        let filter = match self.filter {
+            Some(_) => "Some(fn(&str) -> Result<(), String>)",
+            None => "None",
+        };
+        let value_parser = match self.value_parser {
+            Some(_) => "Some(fn(&str) -> Result<_, String>)",
+            None => "None",
+        };
+        write!(
+            f,
+            "Field {{ \
+                title: {:?}, \
+                description: {:?}, \
+                short: {:?}, \
+                default: {:?}, \
+                filter: {filter}, \
+                value_parser: {value_parser}, \
+                possible_values: {:?}, \
+                case_insensitive: {:?}, \
+                kind: {:?}, \
+                required: {:?}, \
+                multiple: {:?}, \
+                min_values: {:?}, \
+                max_values: {:?}, \
+                conflicts: {:?}, \
+                requires: {:?}, \
+                required_unless: {:?} \
+            }}",
+            self.title,
+            self.description,
+            self.short,
+            self.default,
+            self.possible_values,
+            self.case_insensitive,
+            self.kind,
+            self.required,
+            self.multiple,
+            self.min_values,
+            self.max_values,
+            self.conflicts,
+            self.requires,
+            self.required_unless,
+        )
     }
 }
 
@@ -1010,6 +2461,16 @@ impl PartialEq for Field {
             && self.description == other.description
             && self.short == other.short
             && self.default == other.default
+            && self.possible_values == other.possible_values
+            && self.case_insensitive == other.case_insensitive
+            && self.kind == other.kind
+            && self.required == other.required
+            && self.multiple == other.multiple
+            && self.min_values == other.min_values
+            && self.max_values == other.max_values
+            && self.conflicts == other.conflicts
+            && self.requires == other.requires
+            && self.required_unless == other.required_unless
     }
 }
 
@@ -1073,6 +2534,25 @@ pub struct Flag {
 
     /// The single characer short specified for this Flag.
     short: Option<char>,
+
+    /// Whether this Flag must be present for `Request::validate` to pass.
+    required: bool,
+
+    /// Sibling Field/Flag titles that must not be present alongside this
+    /// one.
+    conflicts: Vec<String>,
+
+    /// Sibling Field/Flag titles that must also be present whenever this one
+    /// is.
+    requires: Vec<String>,
+
+    /// A sibling Field/Flag title whose presence waives this Flag's
+    /// `required` check.
+    required_unless: Option<String>,
+
+    /// Whether this Flag accumulates a count across repeated occurrences,
+    /// rather than collapsing to a single boolean presence.
+    count: bool,
 }
 
 impl Flag {
@@ -1102,6 +2582,11 @@ impl Flag {
             title: String::from(title),
             description: None,
             short: None,
+            required: false,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            required_unless: None,
+            count: false,
         })
     }
 
@@ -1146,75 +2631,385 @@ impl Flag {
         self.short = Some(short);
         self
     }
-}
-
-/// Request<T>.
-///
-/// Requests are the data structure parsed from a Cherry instance. Requests
-/// hold the parsed data and are linked to the Action the application parsed
-/// from. Typical interaction with Requests is to retrieve them from the Cherry
-/// instance through parsing, before running the Action's callback method.
-///
-/// Requests are generic over the type expected to be returned from the Action.
-/// This will be inferred when creating a Request object as the Action is
-/// supplied.
-///
-/// # Example
-/// ```rust
-/// use cherry::{Action, Cherry};
-///
-/// fn main() -> cherry::Result<()> {
-///     let cherry = Cherry::new()
-///         .insert(
-///             Action::new("my_action")?
-///                 .then(|request| {
-///                     // Do something...
-///                 })
-///         )?;
-///     let request = cherry.parse_str("my_action")?;
-///     request.run();
-///     Ok(())
-/// }
-/// ```
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Request<'a, T> {
-    /// The Action this Request is bound to.
-    action: &'a Action<T>,
-
-    /// The Argument values loaded into this Request.
-    arguments: Vec<String>,
-
-    /// The Field values loaded into this Request.
-    fields: HashMap<String, Option<String>>,
-
-    /// The Flag values loaded into this Request.
-    flags: HashMap<String, bool>,
-}
 
-impl<'a, T> Request<'a, T> {
-    /// Create a new Request.
+    /// Mark the Flag as required.
     ///
-    /// Create a new Request instance.
-    pub(crate) fn new(action: &'a Action<T>) -> Self {
-        Self {
-            action,
-            arguments: Vec::new(),
-            fields: action
-                .fields
-                .values()
-                .map(|field| (field.title.to_owned(), field.default.clone()))
-                .collect(),
-            flags: action
-                .flags
-                .values()
-                .map(|flag| (flag.title.to_owned(), false))
-                .collect(),
-        }
-    }
-
-    /// Get an Argument.
+    /// A required Flag must be present for `Request::validate` to pass.
     ///
-    /// Retrieve an Argument value at the specified index.
+    /// # Example
+    /// ```rust
+    /// use cherry::Flag;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let flag = Flag::new("accept_terms")?
+    ///        .required();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Mark the Flag as required, unless a sibling is present.
+    ///
+    /// Behaves as `required`, except `Request::validate` waives the
+    /// requirement when the Field/Flag titled `title` is itself present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Flag;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let flag = Flag::new("accept_terms")?
+    ///        .required_unless("skip_terms");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn required_unless(mut self, title: &str) -> Self {
+        self.required = true;
+        self.required_unless = Some(String::from(title));
+        self
+    }
+
+    /// Declare a sibling Field/Flag this Flag conflicts with.
+    ///
+    /// Repeated calls accumulate rather than replace. `Request::validate`
+    /// returns `Err` if this Flag and `title` are both present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Flag;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let flag = Flag::new("quiet")?.conflicts_with("verbose");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn conflicts_with(mut self, title: &str) -> Self {
+        self.conflicts.push(String::from(title));
+        self
+    }
+
+    /// Declare sibling Fields/Flags this Flag requires.
+    ///
+    /// `Request::validate` returns `Err` for each of `titles` missing
+    /// whenever this Flag is present. Repeated calls accumulate rather than
+    /// replace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Flag;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let flag = Flag::new("force")?.requires(&["confirm"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn requires(mut self, titles: &[&str]) -> Self {
+        self.requires
+            .extend(titles.iter().map(|title| String::from(*title)));
+        self
+    }
+
+    /// Mark the Flag as accumulating a count across repeated occurrences.
+    ///
+    /// A counting Flag's value is no longer a single boolean presence; each
+    /// occurrence on the command line increments it, so `-vvv` can be told
+    /// apart from `-v`. Retrieve the accumulated count with
+    /// `Request::get_flag_count`. A Flag left in the default, non-counting
+    /// mode reports 0 when absent and 1 when present, however many times it
+    /// was supplied.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Flag;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let flag = Flag::new("verbose")?.short('v').count();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn count(mut self) -> Self {
+        self.count = true;
+        self
+    }
+}
+
+/// Value.
+///
+/// The typed value a Field's raw token is parsed into during `Cherry::parse`,
+/// according to the Field's declared `FieldType`. Read back out of a Request
+/// with `Request::field_as`.
+///
+/// # Example
+/// ```rust
+/// use cherry::Value;
+///
+/// let value = Value::Integer(10);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A value parsed (or left verbatim) as a String.
+    String(String),
+    /// A value parsed as a signed 64 bit integer.
+    Integer(i64),
+    /// A value parsed as a 64 bit floating point number.
+    Float(f64),
+    /// A value parsed as a boolean.
+    Bool(bool),
+}
+
+impl Value {
+    /// Parse a raw token into a Value according to a FieldType.
+    ///
+    /// Performs strict full-token parsing: trailing garbage, a bare sign, or
+    /// an empty token are all rejected. Returns `expected`, a human readable
+    /// description of the FieldType, alongside the parse failure so callers
+    /// can build an `Error::InvalidFieldValue`.
+    fn parse(kind: FieldType, value: &str) -> Result<Self, &'static str> {
+        match kind {
+            FieldType::String => Ok(Self::String(String::from(value))),
+            FieldType::Integer => value
+                .parse::<i64>()
+                .map(Self::Integer)
+                .map_err(|_| "an integer"),
+            FieldType::Float => value
+                .parse::<f64>()
+                .map(Self::Float)
+                .map_err(|_| "a float"),
+            FieldType::Bool => value
+                .parse::<bool>()
+                .map(Self::Bool)
+                .map_err(|_| "a boolean"),
+        }
+    }
+}
+
+/// FromValue.
+///
+/// Implemented for each type a Value can hold, allowing `Request::field_as`
+/// to be generic over the requested return type.
+pub trait FromValue: Sized {
+    /// A human readable name for the type, used in type-mismatch Errors.
+    const NAME: &'static str;
+
+    /// Attempt to extract Self from a Value, returning None on a mismatch.
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl FromValue for String {
+    const NAME: &'static str = "a String";
+
+    /// Extract a String from a Value::String.
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for i64 {
+    const NAME: &'static str = "an Integer";
+
+    /// Extract an i64 from a Value::Integer.
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for f64 {
+    const NAME: &'static str = "a Float";
+
+    /// Extract an f64 from a Value::Float.
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for bool {
+    const NAME: &'static str = "a Bool";
+
+    /// Extract a bool from a Value::Bool.
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// ValueSource.
+///
+/// Identifies where a Field's current value came from: supplied on the
+/// command line, or left at the Field's configured `default`. Read back out
+/// of a Request with `Request::field_source`.
+///
+/// # Example
+/// ```rust
+/// use cherry::ValueSource;
+///
+/// let source = ValueSource::Default;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The value was supplied on the command line.
+    CommandLine,
+    /// The value was left at the Field's configured default.
+    Default,
+}
+
+/// Request<T>.
+///
+/// Requests are the data structure parsed from a Cherry instance. Requests
+/// hold the parsed data and are linked to the Action the application parsed
+/// from. Typical interaction with Requests is to retrieve them from the Cherry
+/// instance through parsing, before running the Action's callback method.
+///
+/// Requests are generic over the type expected to be returned from the Action.
+/// This will be inferred when creating a Request object as the Action is
+/// supplied.
+///
+/// # Example
+/// ```rust
+/// use cherry::{Action, Cherry};
+///
+/// fn main() -> cherry::Result<()> {
+///     let cherry = Cherry::new()
+///         .insert(
+///             Action::new("my_action")?
+///                 .then(|request| {
+///                     // Do something...
+///                 })
+///         )?;
+///     let request = cherry.parse_str("my_action")?;
+///     request.run();
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Request<'a, T> {
+    /// The Action this Request is bound to.
+    action: &'a Action<T>,
+
+    /// The Argument values loaded into this Request.
+    arguments: Vec<String>,
+
+    /// The Field values loaded into this Request.
+    fields: HashMap<String, Option<Value>>,
+
+    /// Tracks whether each Field's current value was supplied on the
+    /// command line or is still at its configured default, retrieved
+    /// through `Request::field_source`.
+    field_sources: HashMap<String, ValueSource>,
+
+    /// The raw values collected for `multiple` Fields, in the order
+    /// supplied, retrieved through `Request::get_fields`.
+    field_values: HashMap<String, Vec<String>>,
+
+    /// The Flag values loaded into this Request.
+    flags: HashMap<String, bool>,
+
+    /// The accumulated occurrence count for each `count` Flag, retrieved
+    /// through `Request::get_flag_count`.
+    flag_counts: HashMap<String, u64>,
+
+    /// The Argument values loaded into this Request, converted through each
+    /// Argument's `value_parser`, retrieved through `Request::argument_as`.
+    parsed_arguments: Vec<Option<Rc<dyn Any>>>,
+
+    /// The Field values loaded into this Request, converted through each
+    /// Field's `value_parser`, retrieved through `Request::field_parsed`.
+    parsed_fields: HashMap<String, Rc<dyn Any>>,
+}
+
+impl<'a, T> Debug for Request<'a, T> {
+    /// Format a Request for debug.
+    ///
+    /// Formats the Request for debug printing. The `parsed_arguments` and
+    /// `parsed_fields` maps hold type-erased values and are omitted.
+    ///
+    /// # Error
+    /// Will error if the underlying write macro fails.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Request {{ action: {:?}, arguments: {:?}, fields: {:?}, field_sources: {:?}, field_values: {:?}, flags: {:?}, flag_counts: {:?} }}",
+            self.action, self.arguments, self.fields, self.field_sources, self.field_values, self.flags, self.flag_counts,
+        )
+    }
+}
+
+impl<'a, T> PartialEq for Request<'a, T> {
+    /// Partial Equality implementation.
+    ///
+    /// Defines how Requests should be considered equal. The `parsed_arguments`
+    /// and `parsed_fields` maps hold type-erased values and are not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.action == other.action
+            && self.arguments == other.arguments
+            && self.fields == other.fields
+            && self.field_sources == other.field_sources
+            && self.field_values == other.field_values
+            && self.flags == other.flags
+            && self.flag_counts == other.flag_counts
+    }
+}
+
+impl<'a, T> Request<'a, T> {
+    /// Create a new Request.
+    ///
+    /// Create a new Request instance.
+    pub(crate) fn new(action: &'a Action<T>) -> Self {
+        Self {
+            action,
+            arguments: Vec::new(),
+            fields: action
+                .fields
+                .values()
+                .map(|field| {
+                    let value = field
+                        .default
+                        .as_ref()
+                        .and_then(|default| Value::parse(field.kind, default).ok());
+                    (field.title.to_owned(), value)
+                })
+                .collect(),
+            field_sources: action
+                .fields
+                .values()
+                .filter(|field| field.default.is_some())
+                .map(|field| (field.title.to_owned(), ValueSource::Default))
+                .collect(),
+            field_values: HashMap::new(),
+            flags: action
+                .flags
+                .values()
+                .map(|flag| (flag.title.to_owned(), false))
+                .collect(),
+            flag_counts: HashMap::new(),
+            parsed_arguments: Vec::new(),
+            parsed_fields: HashMap::new(),
+        }
+    }
+
+    /// Get the bound Action's keyword.
+    ///
+    /// Used by `Cherry::dispatch` to resolve the Action's prerequisite chain.
+    pub(crate) fn keyword(&self) -> &str {
+        &self.action.keyword
+    }
+
+    /// Get an Argument.
+    ///
+    /// Retrieve an Argument value at the specified index.
     ///
     /// # Example
     /// ```rust
@@ -1238,133 +3033,451 @@ impl<'a, T> Request<'a, T> {
         self.arguments.get(index)
     }
 
-    /// Get a Field.
+    /// Get all values collected for an Argument.
     ///
-    /// Retrieve a Field value.
+    /// Retrieve every raw value collected for the Argument declared at
+    /// `index`. For a regular, single-valued Argument this is a slice of at
+    /// most one element; for the last Argument on an Action, when declared
+    /// `multiple`, this returns every trailing value collected into it.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::{Action, Cherry, Error, Field};
+    /// use cherry::{Action, Argument, Cherry};
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let cherry = Cherry::new()
     ///         .insert(
     ///             Action::new("my_action")?
-    ///                 .insert_field(Field::new("my_field")?)?
+    ///                 .insert_argument(Argument::new("files")?.multiple(true))?
     ///                 .then(|request| {
     ///                     // Do something...
     ///                 })
     ///         )?;
-    ///     let request = cherry.parse_str("my_action --my_field value")?;
-    ///     request.get_field("my_field").ok_or_else(|| Error::new("Missing field 'my_field'."))?;
+    ///     let request = cherry.parse_str("my_action one two three")?;
+    ///     assert_eq!(3, request.get_argument_values(0).len());
     ///     Ok(())
     /// }
     /// ```
-    pub fn get_field(&self, key: &str) -> Option<&String> {
-        self.fields.get(key)?.as_ref()
+    pub fn get_argument_values(&self, index: usize) -> &[String] {
+        match self.action.arguments.get(index) {
+            Some(definition) if definition.multiple && index == self.action.arguments.len() - 1 => {
+                &self.arguments[index.min(self.arguments.len())..]
+            }
+            Some(_) => self
+                .arguments
+                .get(index)
+                .map_or(&[], std::slice::from_ref),
+            None => &[],
+        }
     }
 
-    /// Get a Flag.
+    /// Get an Argument converted by its value parser.
     ///
-    /// Retrieve a Flag value.
+    /// Retrieve an Argument value at the specified index, converted to the
+    /// type `V` produced by its `Argument::value_parser`. Unlike
+    /// `get_argument`, this returns the parsed type directly rather than a
+    /// `&String`.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::{Action, Cherry, Error, Flag};
+    /// use cherry::{Action, Argument, Cherry};
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let cherry = Cherry::new()
     ///         .insert(
     ///             Action::new("my_action")?
-    ///                 .insert_flag(Flag::new("my_flag")?)?
+    ///                 .insert_argument(
+    ///                     Argument::new("count")?
+    ///                         .value_parser(|value| value.parse::<u32>().map_err(|error| error.to_string())),
+    ///                 )?
     ///                 .then(|request| {
     ///                     // Do something...
     ///                 })
     ///         )?;
-    ///     let request = cherry.parse_str("my_action --my_flag")?;
-    ///     request.get_flag("my_flag").ok_or_else(|| Error::new("Missing flag 'my_flag'."))?;
+    ///     let request = cherry.parse_str("my_action 5")?;
+    ///     let count: u32 = request.argument_as(0)?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn get_flag(&self, key: &str) -> Option<&bool> {
-        self.flags.get(key)
+    ///
+    /// # Error
+    /// Will error if the Argument has no value, or if it was not parsed into
+    /// a `V` by its value parser.
+    pub fn argument_as<V: 'static + Clone>(&self, index: usize) -> error::Result<V> {
+        let name = self
+            .action
+            .arguments
+            .get(index)
+            .map(|argument| argument.title.as_str())
+            .unwrap_or("");
+        let value = self
+            .parsed_arguments
+            .get(index)
+            .and_then(|value| value.as_ref())
+            .ok_or_else(|| Error::missing_argument(&self.action.keyword, name))?;
+
+        value
+            .downcast_ref::<V>()
+            .cloned()
+            .ok_or_else(|| Error::new(&format!("Argument '{name}' is not {}.", std::any::type_name::<V>())))
     }
 
-    /// Query if an Argument exists.
+    /// Get a Field.
     ///
-    /// Query for an Argument at the specified index.
+    /// Retrieve a Field value.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::{Action, Argument, Cherry};
+    /// use cherry::{Action, Cherry, Error, Field};
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let cherry = Cherry::new()
     ///         .insert(
     ///             Action::new("my_action")?
-    ///                 .insert_argument(Argument::new("my_argument")?)?
+    ///                 .insert_field(Field::new("my_field")?)?
     ///                 .then(|request| {
     ///                     // Do something...
     ///                 })
     ///         )?;
-    ///     let request = cherry.parse_str("my_action value")?;
-    ///     if request.has_argument(0) {
-    ///         // Do something...
-    ///     }
+    ///     let request = cherry.parse_str("my_action --my_field value")?;
+    ///     request.get_field("my_field").ok_or_else(|| Error::new("Missing field 'my_field'."))?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn has_argument(&self, index: usize) -> bool {
-        index < self.arguments.len()
+    pub fn get_field(&self, key: &str) -> Option<&String> {
+        match self.fields.get(key)?.as_ref()? {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
     }
 
-    /// Query if a Field exists.
+    /// Get the source of a Field's current value.
     ///
-    /// Query for a Field with the specified key.
+    /// Retrieve whether the Field declared `key` holds a value the user
+    /// supplied on the command line, or is still at its configured
+    /// `default`. Returns `None` if the Field was never supplied and
+    /// carries no default.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::{Action, Cherry, Field};
+    /// use cherry::{Action, Cherry, Field, ValueSource};
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let cherry = Cherry::new()
     ///         .insert(
     ///             Action::new("my_action")?
-    ///                 .insert_field(Field::new("my_field")?)?
+    ///                 .insert_field(Field::new("my_field")?.default("fallback"))?
     ///                 .then(|request| {
     ///                     // Do something...
     ///                 })
     ///         )?;
-    ///     let request = cherry.parse_str("my_action --my_field value")?;
-    ///     if request.has_field("my_field") {
-    ///         // Do something...
-    ///     }
+    ///     let request = cherry.parse_str("my_action")?;
+    ///     assert_eq!(Some(ValueSource::Default), request.field_source("my_field"));
     ///     Ok(())
     /// }
     /// ```
-    pub fn has_field(&self, key: &str) -> bool {
-        self.action.fields.contains_key(key)
+    pub fn field_source(&self, key: &str) -> Option<ValueSource> {
+        self.field_sources.get(key).copied()
     }
 
-    /// Query if a Flag exists.
+    /// Get all values collected for a `multiple` Field.
     ///
-    /// Query for a Flag with the specified key.
+    /// Retrieve every raw value collected for the Field declared `key`, in
+    /// the order supplied. Falls back to a single-element slice holding the
+    /// Field's `default` if the Field was never given a value; returns
+    /// `None` if it was never supplied and carries no default, or if it was
+    /// not marked `Field::multiple`.
     ///
     /// # Example
     /// ```rust
-    /// use cherry::{Action, Cherry, Flag};
+    /// use cherry::{Action, Cherry, Field};
     ///
     /// fn main() -> cherry::Result<()> {
     ///     let cherry = Cherry::new()
     ///         .insert(
     ///             Action::new("my_action")?
-    ///                 .insert_flag(Flag::new("my_flag")?)?
+    ///                 .insert_field(Field::new("tag")?.multiple(true))?
     ///                 .then(|request| {
     ///                     // Do something...
     ///                 })
     ///         )?;
-    ///     let request = cherry.parse_str("my_action --my_flag")?;
-    ///     if request.has_flag("my_flag") {
+    ///     let request = cherry.parse_str("my_action --tag a --tag b")?;
+    ///     assert_eq!(2, request.get_fields("tag").unwrap().len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_fields(&self, key: &str) -> Option<&[String]> {
+        match self.field_values.get(key) {
+            Some(values) => Some(values.as_slice()),
+            None => self
+                .action
+                .fields
+                .get(key)
+                .filter(|field| field.multiple)
+                .and_then(|field| field.default.as_ref())
+                .map(std::slice::from_ref),
+        }
+    }
+
+    /// Get a typed Field value.
+    ///
+    /// Retrieve a Field value, converted to the requested type `V`. Unlike
+    /// `get_field`, this works for any `FieldType`, not just
+    /// `FieldType::String`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Field, FieldType};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_field(Field::new("port")?.kind(FieldType::Integer))?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --port 8080")?;
+    ///     let port: i64 = request.field_as("port")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error if the Field has no value, or if its stored Value does not
+    /// hold a `V`.
+    pub fn field_as<V: FromValue>(&self, key: &str) -> error::Result<V> {
+        let value = self
+            .fields
+            .get(key)
+            .and_then(|value| value.as_ref())
+            .ok_or_else(|| Error::missing_field_value(key))?;
+
+        V::from_value(value)
+            .ok_or_else(|| Error::new(&format!("Field '{key}' is not {}.", V::NAME)))
+    }
+
+    /// Get a Field converted by its value parser.
+    ///
+    /// Retrieve a Field value, converted to the type `V` produced by its
+    /// `Field::value_parser`. Unlike `field_as`, this works with any type the
+    /// parser produces, not just the closed set of `FieldType`/`Value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Field};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_field(
+    ///                     Field::new("port")?
+    ///                         .value_parser(|value| value.parse::<u16>().map_err(|error| error.to_string())),
+    ///                 )?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --port 8080")?;
+    ///     let port: u16 = request.field_parsed("port")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error if the Field has no parsed value, or if it was not parsed
+    /// into a `V` by its value parser.
+    pub fn field_parsed<V: 'static + Clone>(&self, key: &str) -> error::Result<V> {
+        let value = self
+            .parsed_fields
+            .get(key)
+            .ok_or_else(|| Error::missing_field_value(key))?;
+
+        value
+            .downcast_ref::<V>()
+            .cloned()
+            .ok_or_else(|| Error::new(&format!("Field '{key}' is not {}.", std::any::type_name::<V>())))
+    }
+
+    /// Get a Field converted by its value parser, if supplied.
+    ///
+    /// Retrieve a Field value, converted to the type `V` produced by its
+    /// `Field::value_parser`. Unlike `field_parsed`, this distinguishes a
+    /// Field that was never supplied (`Ok(None)`) from one whose parsed
+    /// value does not downcast to `V` (`Err`), rather than treating both as
+    /// an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Field};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_field(
+    ///                     Field::new("port")?
+    ///                         .value_parser(|value| value.parse::<u16>().map_err(|error| error.to_string())),
+    ///                 )?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action")?;
+    ///     let port: Option<u16> = request.get_field_parsed("port")?;
+    ///     assert_eq!(None, port);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error if the Field was supplied but its parsed value was not a
+    /// `V`.
+    pub fn get_field_parsed<V: 'static + Clone>(&self, key: &str) -> error::Result<Option<V>> {
+        self.parsed_fields
+            .get(key)
+            .map(|value| {
+                value.downcast_ref::<V>().cloned().ok_or_else(|| {
+                    Error::new(&format!("Field '{key}' is not {}.", std::any::type_name::<V>()))
+                })
+            })
+            .transpose()
+    }
+
+    /// Get a Flag.
+    ///
+    /// Retrieve a Flag value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Error, Flag};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_flag(Flag::new("my_flag")?)?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --my_flag")?;
+    ///     request.get_flag("my_flag").ok_or_else(|| Error::new("Missing flag 'my_flag'."))?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_flag(&self, key: &str) -> Option<&bool> {
+        self.flags.get(key)
+    }
+
+    /// Get a Flag's occurrence count.
+    ///
+    /// Retrieve how many times a `count` Flag occurred on the command line.
+    /// A Flag not marked `count` instead reports 0 if absent or 1 if
+    /// present, however many times it was supplied.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Flag};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_flag(Flag::new("verbose")?.short('v').count())?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action -vvv")?;
+    ///     assert_eq!(3, request.get_flag_count("verbose"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_flag_count(&self, key: &str) -> u64 {
+        self.flag_counts
+            .get(key)
+            .copied()
+            .unwrap_or_else(|| u64::from(self.flags.get(key).copied().unwrap_or(false)))
+    }
+
+    /// Query if an Argument exists.
+    ///
+    /// Query for an Argument at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Argument, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_argument(Argument::new("my_argument")?)?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action value")?;
+    ///     if request.has_argument(0) {
+    ///         // Do something...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn has_argument(&self, index: usize) -> bool {
+        index < self.arguments.len()
+    }
+
+    /// Query if a Field exists.
+    ///
+    /// Query for a Field with the specified key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Field};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_field(Field::new("my_field")?)?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --my_field value")?;
+    ///     if request.has_field("my_field") {
+    ///         // Do something...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn has_field(&self, key: &str) -> bool {
+        self.action.fields.contains_key(key)
+    }
+
+    /// Query if a Flag exists.
+    ///
+    /// Query for a Flag with the specified key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Flag};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_flag(Flag::new("my_flag")?)?
+    ///                 .then(|request| {
+    ///                     // Do something...
+    ///                 })
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --my_flag")?;
+    ///     if request.has_flag("my_flag") {
     ///         // Do something...
     ///     }
     ///     Ok(())
@@ -1383,45 +3496,127 @@ impl<'a, T> Request<'a, T> {
     /// Will return an Error if attempting to add too many Arguments to the Request
     /// for the Action, or if an Argument filter method fails.
     pub(crate) fn insert_argument(mut self, argument: &str) -> error::Result<Self> {
-        let filter = self
-            .action
-            .arguments
-            .get(self.arguments.len())
-            .ok_or_else(|| Error::new("Todo: Help."))?
-            .filter
-            .as_ref();
+        let index = self.arguments.len();
+        let definition = match self.action.arguments.get(index) {
+            Some(definition) => definition,
+            None => self
+                .action
+                .arguments
+                .last()
+                .filter(|last| last.multiple)
+                .ok_or_else(|| {
+                    Error::new(&format!(
+                        "Action '{}' does not accept any more arguments.",
+                        self.action.keyword
+                    ))
+                })?,
+        };
 
-        match filter {
-            Some(callback) if !callback(argument) => Err(Error::new("Todo: Help.")),
-            _ => {
-                self.arguments.push(String::from(argument));
-                Ok(self)
+        if definition.multiple {
+            let last_index = self.action.arguments.len() - 1;
+            let collected = index - last_index + 1;
+            if definition.max_values.map_or(false, |max| collected > max) {
+                return Err(Error::new(&format!(
+                    "Argument '{}' accepts at most {} value(s).",
+                    definition.title,
+                    definition.max_values.unwrap_or_default()
+                )));
+            }
+        }
+
+        if let Some(choices) = &definition.possible_values {
+            let names: Vec<String> = choices.iter().map(|(value, _)| value.clone()).collect();
+            if !names
+                .iter()
+                .any(|name| matches_possible_value(argument, name, definition.case_insensitive))
+            {
+                return Err(Error::invalid_choice(&definition.title, argument, &names));
             }
         }
+
+        if let Some(callback) = &definition.filter {
+            callback(argument)
+                .map_err(|message| Error::invalid_value(&definition.title, argument, &message))?;
+        }
+
+        let parsed = match &definition.value_parser {
+            Some(parser) => Some(
+                parser(argument)
+                    .map_err(|message| Error::invalid_value(&definition.title, argument, &message))?,
+            ),
+            None => None,
+        };
+        self.parsed_arguments.push(parsed);
+        self.arguments.push(String::from(argument));
+        Ok(self)
     }
 
     /// Insert a Field.
     ///
     /// Insert a Field into this Request. Fields are defined on the Action and the
-    /// actual Field values loaded into the Request.
+    /// actual Field values loaded into the Request. The value is parsed
+    /// according to the Field's declared `kind` before the `filter` runs, so
+    /// a value that does not even match the declared `FieldType` is reported
+    /// as an `InvalidFieldValue` rather than a filter failure.
     ///
     /// # Error
-    /// Will error if the Flag is not found in the Action, or if a Field filter
-    /// method fails.
+    /// Will error if the Field is not found in the Action, if the value does
+    /// not parse as the Field's declared `kind`, if a Field filter method
+    /// rejects the value (surfacing its reason through `Error::InvalidValue`),
+    /// or if a `multiple` Field is given more than its `max_values`
+    /// occurrences.
     pub(crate) fn insert_field(mut self, field: &str, value: &str) -> error::Result<Self> {
+        let keyword = &self.action.keyword;
         let field = self
             .action
             .fields
             .get(field)
-            .ok_or_else(|| Error::new("Todo: Help."))?;
-        match &field.filter {
-            Some(callback) if !callback(value) => Err(Error::new("Todo: Help.")),
-            _ => {
-                self.fields
-                    .insert(field.title.to_owned(), Some(String::from(value)));
-                Ok(self)
+            .ok_or_else(|| Error::new(&format!("Unknown field '{field}' for action '{keyword}'.")))?;
+
+        if field.multiple {
+            let collected = self.field_values.get(&field.title).map_or(0, Vec::len) + 1;
+            if field.max_values.map_or(false, |max| collected > max) {
+                return Err(Error::new(&format!(
+                    "Field '{}' accepts at most {} value(s).",
+                    field.title,
+                    field.max_values.unwrap_or_default()
+                )));
+            }
+        }
+
+        if let Some(choices) = &field.possible_values {
+            let names: Vec<String> = choices.iter().map(|(name, _)| name.clone()).collect();
+            if !names
+                .iter()
+                .any(|name| matches_possible_value(value, name, field.case_insensitive))
+            {
+                return Err(Error::invalid_choice(&field.title, value, &names));
             }
         }
+
+        let parsed = Value::parse(field.kind, value)
+            .map_err(|expected| Error::invalid_field_value(&field.title, value, expected))?;
+
+        if let Some(callback) = &field.filter {
+            callback(value).map_err(|message| Error::invalid_value(&field.title, value, &message))?;
+        }
+
+        if let Some(parser) = &field.value_parser {
+            let parsed_value = parser(value)
+                .map_err(|message| Error::invalid_value(&field.title, value, &message))?;
+            self.parsed_fields.insert(field.title.to_owned(), parsed_value);
+        }
+
+        if field.multiple {
+            self.field_values
+                .entry(field.title.to_owned())
+                .or_default()
+                .push(String::from(value));
+        }
+        self.fields.insert(field.title.to_owned(), Some(parsed));
+        self.field_sources
+            .insert(field.title.to_owned(), ValueSource::CommandLine);
+        Ok(self)
     }
 
     /// Insert a Flag.
@@ -1433,9 +3628,19 @@ impl<'a, T> Request<'a, T> {
     /// Will error if the Flag is not found in the Action.
     pub(crate) fn insert_flag(mut self, flag: &str) -> error::Result<Self> {
         match self.action.flags.get(flag) {
-            None => Err(Error::new("Todo: Help.")),
+            None => {
+                let candidates = self.action.ordered_flags();
+                Err(Error::unknown_flag(
+                    &self.action.keyword,
+                    flag,
+                    candidates.iter().map(|candidate| candidate.title.as_str()),
+                ))
+            }
             Some(value) => {
                 self.flags.insert(value.title.to_owned(), true);
+                if value.count {
+                    *self.flag_counts.entry(value.title.to_owned()).or_insert(0) += 1;
+                }
                 Ok(self)
             }
         }
@@ -1468,10 +3673,25 @@ impl<'a, T> Request<'a, T> {
         self.action.run(self)
     }
 
+    /// Query if a Field or Flag title was supplied a value during parsing.
+    ///
+    /// Used to resolve `required_unless`, `conflicts_with` and `requires`
+    /// constraints, which are declared against the shared Field/Flag title
+    /// namespace rather than either kind specifically.
+    fn is_present(&self, title: &str) -> bool {
+        matches!(self.field_sources.get(title), Some(ValueSource::CommandLine))
+            || self.flags.get(title).copied().unwrap_or(false)
+    }
+
     /// Validate the Request.
     ///
-    /// Validate the Request by ensuring that enough Arguments, Fields and Flags
-    /// have been supplied.
+    /// Validate the Request by ensuring that enough Arguments have been
+    /// supplied and that every required Field and Flag is present, that no
+    /// `conflicts_with` pair is present together, and that every `requires`
+    /// edge whose antecedent is present also has its consequent. Unlike a
+    /// bare pass/fail check, every shortfall is collected and returned
+    /// together, rather than stopping at the first one found. Group rules
+    /// are checked separately, by `validate_groups`.
     ///
     /// # Example
     /// ```rust
@@ -1486,39 +3706,202 @@ impl<'a, T> Request<'a, T> {
     ///         )?;
     ///     let request = cherry.parse_str("my_action value")?;
     ///     match request.validate() {
-    ///        true => Ok(()),
-    ///        false => Err(cherry::Error::new("Invalid!")),
+    ///        Ok(()) => Ok(()),
+    ///        Err(missing) => Err(cherry::Error::new(&missing.join(" "))),
     ///     }
     /// }
     /// ```
-    pub fn validate(&self) -> bool {
-        self.arguments.len() == self.action.arguments.len()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-
-    /// Action::new must create as per struct initialisation.
     ///
-    /// The new method on Action must create an object as per the struct
-    /// initialiser syntax.
-    #[test]
-    fn action_new() {
-        let expected = Action {
-            keyword: String::from("my_action"),
-            description: None,
-            arguments: Vec::new(),
-            fields: HashMap::new(),
-            flags: HashMap::new(),
-            then: None,
+    /// # Error
+    /// Will return `Err` with one descriptive message per unmet Argument,
+    /// Field or Flag requirement, violated `conflicts_with`, or unmet
+    /// `requires`.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        let multiple = self.action.arguments.last().is_some_and(|last| last.multiple);
+        let expected_arguments = match self.action.arguments.last() {
+            Some(last) if last.multiple => {
+                self.action.arguments.len() - 1 + last.min_values.unwrap_or(1)
+            }
+            _ => self.action.arguments.len(),
         };
-        let actual = Action::<()>::new("my_action").unwrap();
+        let valid_arguments = if multiple {
+            self.arguments.len() >= expected_arguments
+        } else {
+            self.arguments.len() == expected_arguments
+        };
+        if !valid_arguments {
+            errors.push(format!(
+                "Expected {} argument(s), found {}.",
+                expected_arguments,
+                self.arguments.len()
+            ));
+        }
 
-        assert_eq!(expected, actual);
-    }
+        for field in self.action.ordered_fields() {
+            let satisfied = match field.multiple {
+                true => {
+                    self.get_fields(&field.title).map_or(0, |values| values.len())
+                        >= field.min_values.unwrap_or(1)
+                }
+                false => self
+                    .fields
+                    .get(&field.title)
+                    .map_or(false, |value| value.is_some()),
+            };
+            let waived = field
+                .required_unless
+                .as_ref()
+                .is_some_and(|sibling| self.is_present(sibling));
+            if field.required && !satisfied && !waived {
+                errors.push(format!("Field '{}' is required.", field.title));
+            }
+
+            if self.is_present(&field.title) {
+                for conflict in &field.conflicts {
+                    if self.is_present(conflict) {
+                        errors.push(format!(
+                            "Field '{}' conflicts with '{}'.",
+                            field.title, conflict
+                        ));
+                    }
+                }
+                for requirement in &field.requires {
+                    if !self.is_present(requirement) {
+                        errors.push(format!(
+                            "Field '{}' requires '{}'.",
+                            field.title, requirement
+                        ));
+                    }
+                }
+            }
+        }
+
+        for flag in self.action.ordered_flags() {
+            let satisfied = self.flags.get(&flag.title).copied().unwrap_or(false);
+            let waived = flag
+                .required_unless
+                .as_ref()
+                .is_some_and(|sibling| self.is_present(sibling));
+            if flag.required && !satisfied && !waived {
+                errors.push(format!("Flag '{}' is required.", flag.title));
+            }
+
+            if self.is_present(&flag.title) {
+                for conflict in &flag.conflicts {
+                    if self.is_present(conflict) {
+                        errors.push(format!(
+                            "Flag '{}' conflicts with '{}'.",
+                            flag.title, conflict
+                        ));
+                    }
+                }
+                for requirement in &flag.requires {
+                    if !self.is_present(requirement) {
+                        errors.push(format!(
+                            "Flag '{}' requires '{}'.",
+                            flag.title, requirement
+                        ));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate the Request against the Action's declared Groups.
+    ///
+    /// Checks every Group declared on the matched Action against the Fields
+    /// and Flags collected into this Request, returning a descriptive
+    /// `Error::GroupViolation` for the first mutual-exclusion or
+    /// requirement rule broken.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Field, Group};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_field(Field::new("json")?)?
+    ///                 .insert_field(Field::new("yaml")?)?
+    ///                 .insert_group(
+    ///                     Group::new("format")?
+    ///                         .args(&["json", "yaml"])
+    ///                         .multiple(false),
+    ///                 )?
+    ///                 .then(|_request| ())
+    ///         )?;
+    ///     let request = cherry.parse_str("my_action --json a")?;
+    ///     assert!(request.validate_groups().is_ok());
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error with `Error::GroupViolation` if more than one of a
+    /// non-`multiple` Group's args are present, or if none of a `required`
+    /// Group's args are present.
+    pub fn validate_groups(&self) -> error::Result<()> {
+        for group in &self.action.groups {
+            let present: Vec<&String> = group.args.iter().filter(|arg| self.is_present(arg)).collect();
+
+            if !group.multiple && present.len() > 1 {
+                return Err(Error::group_violation(
+                    &group.title,
+                    &format!(
+                        "argument '--{}' cannot be used with '--{}'",
+                        present[0], present[1]
+                    ),
+                ));
+            }
+
+            if group.required && present.is_empty() {
+                let args: Vec<String> = group.args.iter().map(|arg| format!("'--{arg}'")).collect();
+                return Err(Error::group_violation(
+                    &group.title,
+                    &format!("one of {} is required", args.join(", ")),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Action::new must create as per struct initialisation.
+    ///
+    /// The new method on Action must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn action_new() {
+        let expected = Action {
+            keyword: String::from("my_action"),
+            description: None,
+            arguments: Vec::new(),
+            fields: HashMap::new(),
+            flags: HashMap::new(),
+            children: HashMap::new(),
+            groups: Vec::new(),
+            requires: Vec::new(),
+            then: None,
+        };
+        let actual = Action::<()>::new("my_action").unwrap();
+
+        assert_eq!(expected, actual);
+    }
 
     /// Action::new must error on empty keyword.
     ///
@@ -1581,6 +3964,24 @@ mod tests {
         assert_eq!(expected, actual.unwrap_err());
     }
 
+    /// Action::insert_argument must error after a `multiple` Argument.
+    ///
+    /// The insert argument method must error when attempting to insert an
+    /// Argument after one already marked `multiple`, as a variadic Argument
+    /// must be the last one declared.
+    #[test]
+    fn action_insert_argument_after_multiple() {
+        let expected = Error::new("Argument 'second' cannot follow a multiple-valued Argument.");
+
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("first").unwrap().multiple(true))
+            .unwrap()
+            .insert_argument(Argument::new("second").unwrap());
+
+        assert_eq!(expected, actual.unwrap_err());
+    }
+
     /// Action::insert_field must insert a Field.
     ///
     /// The insert field method must correctly insert a Field into the internal
@@ -1686,6 +4087,67 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Action::insert_field must error when the default is outside its
+    /// possible_values set.
+    ///
+    /// The insert field method must error when attempting to insert a Field
+    /// whose default value is not itself one of its own possible_values.
+    #[test]
+    fn action_insert_field_default_outside_possible_values() {
+        let expected = Error::invalid_choice(
+            "my_field",
+            "purple",
+            &[String::from("red"), String::from("green")],
+        );
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("my_field")
+                    .unwrap()
+                    .possible_values(&["red", "green"])
+                    .default("purple"),
+            )
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Action::insert_field must accept a default within its possible_values
+    /// set.
+    ///
+    /// The insert field method must succeed when the Field's default value is
+    /// itself one of its own possible_values.
+    #[test]
+    fn action_insert_field_default_within_possible_values() {
+        let actual = Action::<()>::new("my_action").unwrap().insert_field(
+            Field::new("my_field")
+                .unwrap()
+                .possible_values(&["red", "green"])
+                .default("red"),
+        );
+
+        assert!(actual.is_ok());
+    }
+
+    /// Action::insert_field must accept a default matching possible_values
+    /// only by case when case_insensitive is set.
+    ///
+    /// The insert field method must succeed when the Field's default value
+    /// matches a possible_values entry in a different case and
+    /// case_insensitive is set.
+    #[test]
+    fn action_insert_field_default_case_insensitive() {
+        let actual = Action::<()>::new("my_action").unwrap().insert_field(
+            Field::new("my_field")
+                .unwrap()
+                .possible_values(&["red", "green"])
+                .case_insensitive()
+                .default("RED"),
+        );
+
+        assert!(actual.is_ok());
+    }
+
     /// Action::insert_flag must insert a Flag.
     ///
     /// The insert flag method must correctly insert a Flag into the internal
@@ -1791,6 +4253,293 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Action::insert_child must insert a child Action.
+    ///
+    /// The insert child method must correctly insert a child Action into the
+    /// internal HashMap.
+    #[test]
+    fn action_insert_child() {
+        let mut map = HashMap::new();
+        map.insert(
+            String::from("my_child"),
+            Action::<()>::new("my_child").unwrap(),
+        );
+
+        let mut expected = Action::<()>::new("my_action").unwrap();
+        expected.children = map;
+
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_child(Action::new("my_child").unwrap())
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Action::requires must register a prerequisite keyword.
+    ///
+    /// The requires method must append the provided keyword to the Action's
+    /// prerequisite list, retrievable via prerequisites.
+    #[test]
+    fn action_requires() {
+        let mut expected = Action::<()>::new("my_action").unwrap();
+        expected.requires = vec![String::from("build")];
+
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .requires("build");
+
+        assert_eq!(expected, actual);
+        assert_eq!(&[String::from("build")], actual.prerequisites());
+    }
+
+    /// Action::requires must accumulate multiple prerequisites in order.
+    ///
+    /// Successive calls to requires must append further keywords, preserving
+    /// declaration order.
+    #[test]
+    fn action_requires_multiple() {
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .requires("build")
+            .requires("test");
+
+        assert_eq!(
+            &[String::from("build"), String::from("test")],
+            actual.prerequisites()
+        );
+    }
+
+    /// Action::insert_group must register a Group referencing a Field.
+    ///
+    /// The insert_group method must accept a Group naming an already
+    /// registered Field.
+    #[test]
+    fn action_insert_group() {
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("json").unwrap())
+            .unwrap()
+            .insert_field(Field::new("yaml").unwrap())
+            .unwrap()
+            .insert_group(Group::new("format").unwrap().args(&["json", "yaml"]))
+            .unwrap();
+
+        assert_eq!(1, actual.groups.len());
+    }
+
+    /// Action::insert_group must error when naming no args.
+    ///
+    /// The insert_group method must error when the Group references no
+    /// Field or Flag titles.
+    #[test]
+    fn action_insert_group_empty_args() {
+        let expected = Error::new("Group 'format' must reference at least one Field or Flag.");
+
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_group(Group::new("format").unwrap());
+
+        assert_eq!(expected, actual.unwrap_err());
+    }
+
+    /// Action::insert_group must error when naming an unknown title.
+    ///
+    /// The insert_group method must error when the Group references a title
+    /// not already registered as a Field or Flag.
+    #[test]
+    fn action_insert_group_unknown_title() {
+        let expected =
+            Error::new("Group 'format' references unknown Field or Flag 'json'.");
+
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_group(Group::new("format").unwrap().args(&["json"]));
+
+        assert_eq!(expected, actual.unwrap_err());
+    }
+
+    /// Action::insert_child must error with empty child keyword.
+    ///
+    /// The insert child method must error when attempting to insert a child
+    /// Action with an empty string keyword.
+    #[test]
+    fn action_insert_child_empty() {
+        let expected = Error::new("Action must have a non-empty keyword.");
+
+        let action = Action::<()>::new("my_action").unwrap();
+        let mut child = Action::new("my_child").unwrap();
+        child.keyword = String::from("");
+        let actual = action.insert_child(child);
+
+        assert_eq!(expected, actual.unwrap_err());
+    }
+
+    /// Action::insert_child must error when a collision occurs.
+    ///
+    /// The insert child method must error when attempting to insert a child
+    /// Action with a duplicate keyword.
+    #[test]
+    fn action_insert_child_collision() {
+        let expected = Error::new("Action 'my_action' already contains a child Action 'my_child'.");
+        let actual = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_child(Action::new("my_child").unwrap())
+            .unwrap()
+            .insert_child(Action::new("my_child").unwrap())
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Action::get_child must retrieve an inserted child Action.
+    ///
+    /// The get child method must retrieve the child Action matching the
+    /// provided keyword.
+    #[test]
+    fn action_get_child() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_child(Action::new("my_child").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            Some(&Action::<()>::new("my_child").unwrap()),
+            action.get_child("my_child")
+        );
+    }
+
+    /// Action::get_child must return None if the child does not exist.
+    ///
+    /// The get child method must return None when no child Action matches the
+    /// provided keyword.
+    #[test]
+    fn action_get_child_not_found() {
+        let action = Action::<()>::new("my_action").unwrap();
+
+        assert_eq!(None, action.get_child("my_child"));
+    }
+
+    /// Action::help must render the usage line, arguments, fields and flags.
+    ///
+    /// The help method must produce a clap-style usage block covering every
+    /// Argument, Field and Flag stored on the Action.
+    #[test]
+    fn action_help_render() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .description("My action.")
+            .insert_argument(
+                Argument::new("name")
+                    .unwrap()
+                    .description("The name to use."),
+            )
+            .unwrap()
+            .insert_field(
+                Field::new("output")
+                    .unwrap()
+                    .short('o')
+                    .description("Where to write output.")
+                    .default("out.txt"),
+            )
+            .unwrap()
+            .insert_flag(
+                Flag::new("verbose")
+                    .unwrap()
+                    .short('v')
+                    .description("Run verbosely."),
+            )
+            .unwrap();
+
+        let actual = action.help(&[], &[]);
+
+        assert!(actual.starts_with("USAGE:\n    my_action [OPTIONS] <name>\n"));
+        assert!(actual.contains("My action."));
+        assert!(actual.contains("ARGUMENTS:"));
+        assert!(actual.contains("<name>"));
+        assert!(actual.contains("The name to use."));
+        assert!(actual.contains("FIELDS:"));
+        assert!(actual.contains("-o, --output <VALUE>"));
+        assert!(actual.contains("(default: out.txt)"));
+        assert!(actual.contains("FLAGS:"));
+        assert!(actual.contains("-v, --verbose"));
+    }
+
+    /// Action::help must render possible_values alongside Arguments and Fields.
+    ///
+    /// The help method must append a `[possible values: ...]` suffix and, for
+    /// any value carrying a description, an indented description line.
+    #[test]
+    fn action_help_possible_values() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("command")
+                    .unwrap()
+                    .possible_values(&["add", "remove"]),
+            )
+            .unwrap()
+            .insert_field(
+                Field::new("mode")
+                    .unwrap()
+                    .possible_value("add", "Add an item")
+                    .possible_value("remove", "Remove an item"),
+            )
+            .unwrap();
+
+        let actual = action.help(&[], &[]);
+
+        assert!(actual.contains("<command>"));
+        assert!(actual.contains("[possible values: add, remove]"));
+        assert!(actual.contains("--mode <VALUE>"));
+        assert!(actual.contains("add             Add an item"));
+        assert!(actual.contains("remove          Remove an item"));
+    }
+
+    /// Action::help must descend into children to find the deepest match.
+    ///
+    /// The help method must walk a path of child keywords and render the
+    /// usage block for the deepest Action located along that path.
+    #[test]
+    fn action_help_descend_children() {
+        let action = Action::<()>::new("parent")
+            .unwrap()
+            .insert_child(
+                Action::new("child")
+                    .unwrap()
+                    .description("The child action."),
+            )
+            .unwrap();
+
+        let actual = action.help(&[], &["child"]);
+
+        assert!(actual.starts_with("USAGE:\n    parent child\n"));
+        assert!(actual.contains("The child action."));
+    }
+
+    /// Action::help must list child Actions under an ACTIONS section.
+    ///
+    /// The help method must include every registered child Action, along with
+    /// its description, when rendering a parent's usage block.
+    #[test]
+    fn action_help_lists_children() {
+        let action = Action::<()>::new("parent")
+            .unwrap()
+            .insert_child(
+                Action::new("child")
+                    .unwrap()
+                    .description("The child action."),
+            )
+            .unwrap();
+
+        let actual = action.help(&[], &[]);
+
+        assert!(actual.contains("USAGE:\n    parent [ACTION]\n"));
+        assert!(actual.contains("ACTIONS:"));
+        assert!(actual.contains("child"));
+        assert!(actual.contains("The child action."));
+    }
+
     /// Action::run must correctly run the method.
     ///
     /// The run method must correctly call the Action's then callback with the
@@ -1827,16 +4576,16 @@ mod tests {
         assert_eq!(error, action.run(request).unwrap_err());
     }
 
-    /// Action::run must error when no then callback.
+    /// Action::run must error with help text when no then callback.
     ///
-    /// The run method must return an Error when the Action does not have a set
-    /// then callback
+    /// The run method must return an Error carrying this Action's rendered
+    /// help text when the Action does not have a set then callback.
     #[test]
     fn action_run_missing_then() {
         let action = Action::<()>::new("my_action").unwrap();
 
         let request = Request::new(&action);
-        let error = Error::new("Todo: Help.");
+        let error = Error::new(&action.help(&[], &[]));
 
         assert_eq!(error, action.run(request).unwrap_err());
     }
@@ -1869,30 +4618,99 @@ mod tests {
         assert!(action.then.is_some());
     }
 
-    /// Action::fmt must debug the Action.
+    /// Action::assert must pass a consistent Action.
     ///
-    /// The custom implementation of the Debug::fmt method must correctly display
-    /// the Action.
+    /// The assert method must not panic when every Field/Flag reference
+    /// resolves to a declared Field or Flag.
     #[test]
-    fn action_fmt() {
-        let action = Action::new("action")
-            .unwrap()
-            .description("Action description.")
-            .insert_argument(Argument::new("my_argument").unwrap())
+    fn action_assert_consistent() {
+        let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap())
+            .insert_field(Field::new("config").unwrap().conflicts_with("id"))
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_field(Field::new("id").unwrap().requires(&["verbose"]))
             .unwrap()
-            .then(|_| {});
-        let expected = "Action { \
+            .insert_flag(Flag::new("verbose").unwrap().required_unless("config"))
+            .unwrap();
+
+        action.assert();
+    }
+
+    /// Action::assert must panic on a dangling conflicts_with reference.
+    ///
+    /// The assert method must panic if a Field's conflicts_with names a
+    /// Field/Flag the Action does not declare.
+    #[test]
+    #[should_panic(expected = "references unknown name")]
+    fn action_assert_dangling_conflicts_with() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("config").unwrap().conflicts_with("missing"))
+            .unwrap();
+
+        action.assert();
+    }
+
+    /// Action::assert must panic on a dangling requires reference.
+    ///
+    /// The assert method must panic if a Flag's requires names a Field/Flag
+    /// the Action does not declare.
+    #[test]
+    #[should_panic(expected = "references unknown name")]
+    fn action_assert_dangling_requires() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap().requires(&["missing"]))
+            .unwrap();
+
+        action.assert();
+    }
+
+    /// Action::assert must panic on a dangling required_unless reference.
+    ///
+    /// The assert method must panic if a Field's required_unless names a
+    /// Field/Flag the Action does not declare.
+    #[test]
+    #[should_panic(expected = "references unknown name")]
+    fn action_assert_dangling_required_unless() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("config").unwrap().required_unless("missing"))
+            .unwrap();
+
+        action.assert();
+    }
+
+    /// Action::fmt must debug the Action.
+    ///
+    /// The custom implementation of the Debug::fmt method must correctly display
+    /// the Action.
+    #[test]
+    fn action_fmt() {
+        let action = Action::new("action")
+            .unwrap()
+            .description("Action description.")
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap())
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap()
+            .then(|_| {});
+        let expected = "Action { \
                 keyword: \"action\", \
                 description: Some(\"Action description.\"), \
                 arguments: [\
                     Argument { \
                         title: \"my_argument\", \
                         description: None, \
-                        filter: None \
+                        filter: None, \
+                        value_parser: None, \
+                        possible_values: None, \
+                        case_insensitive: false, \
+                        multiple: false, \
+                        min_values: None, \
+                        max_values: None \
                     }\
                 ], \
                 fields: {\
@@ -1901,16 +4719,35 @@ mod tests {
                         description: None, \
                         short: None, \
                         default: None, \
-                        filter: None \
+                        filter: None, \
+                        value_parser: None, \
+                        possible_values: None, \
+                        case_insensitive: false, \
+                        kind: String, \
+                        required: false, \
+                        multiple: false, \
+                        min_values: None, \
+                        max_values: None, \
+                        conflicts: [], \
+                        requires: [], \
+                        required_unless: None \
                     }\
                 }, \
                 flags: {\
                     \"my_flag\": Flag { \
                         title: \"my_flag\", \
                         description: None, \
-                        short: None \
+                        short: None, \
+                        required: false, \
+                        conflicts: [], \
+                        requires: [], \
+                        required_unless: None, \
+                        count: false \
                     }\
                 }, \
+                children: {}, \
+                groups: [], \
+                requires: [], \
                 then: Some(fn(Request<T>) -> T) \
             }";
         let actual = format!("{:?}", action);
@@ -1931,6 +4768,9 @@ mod tests {
                 arguments: [], \
                 fields: {}, \
                 flags: {}, \
+                children: {}, \
+                groups: [], \
+                requires: [], \
                 then: None \
             }";
         let actual = format!("{:?}", action);
@@ -1938,6 +4778,71 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Group::new must create as per struct initialisation.
+    ///
+    /// The new method on Group must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn group_new() {
+        let expected = Group {
+            title: String::from("format"),
+            args: Vec::new(),
+            required: false,
+            multiple: true,
+        };
+        let actual = Group::new("format").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Group::new must error on empty title.
+    ///
+    /// The new method must correctly error when provided with an empty title
+    /// during initialisation.
+    #[test]
+    fn group_new_empty() {
+        let expected = Error::new("Group must have a non-empty title.");
+        let actual = Group::new("");
+
+        assert_eq!(expected, actual.unwrap_err());
+    }
+
+    /// Group::args must set the governed Field/Flag titles.
+    ///
+    /// The args method must replace the Group's args with the provided
+    /// titles.
+    #[test]
+    fn group_args() {
+        let actual = Group::new("format").unwrap().args(&["json", "yaml"]);
+
+        assert_eq!(
+            vec![String::from("json"), String::from("yaml")],
+            actual.args
+        );
+    }
+
+    /// Group::required must set the required flag.
+    ///
+    /// The required method must set whether at least one of the Group's args
+    /// must be supplied.
+    #[test]
+    fn group_required() {
+        let actual = Group::new("format").unwrap().required(true);
+
+        assert!(actual.required);
+    }
+
+    /// Group::multiple must set the multiple flag.
+    ///
+    /// The multiple method must set whether more than one of the Group's
+    /// args may be supplied together.
+    #[test]
+    fn group_multiple() {
+        let actual = Group::new("format").unwrap().multiple(false);
+
+        assert!(!actual.multiple);
+    }
+
     /// Argument::new must create as per struct initialisation.
     ///
     /// The new method on Argument must create an object as per the struct
@@ -1948,6 +4853,12 @@ mod tests {
             title: String::from("Title"),
             description: None,
             filter: None,
+            value_parser: None,
+            possible_values: None,
+            case_insensitive: false,
+            multiple: false,
+            min_values: None,
+            max_values: None,
         };
         let actual = Argument::new("Title").unwrap();
 
@@ -1992,6 +4903,26 @@ mod tests {
         assert!(argument.filter.is_some());
     }
 
+    /// Argument::filter must correctly set the filter callback with a
+    /// reason-returning closure.
+    ///
+    /// The filter method must correctly set the internal Argument filter
+    /// callback when passed a closure returning `Result<(), String>`.
+    #[test]
+    fn argument_filter_closure_with_reason() {
+        let argument = Argument::new("my_argument").unwrap().filter(
+            |value: &str| -> Result<(), String> {
+                if value == "Hello" {
+                    Ok(())
+                } else {
+                    Err(String::from("must be 'Hello'"))
+                }
+            },
+        );
+
+        assert!(argument.filter.is_some());
+    }
+
     /// Argument::filter must correctly set the filter callback with a method.
     ///
     /// The filter method must correctly set the internal Argument filter callback when
@@ -2019,7 +4950,13 @@ mod tests {
         let expected = "Argument { \
                 title: \"argument\", \
                 description: Some(\"Argument description.\"), \
-                filter: Some(fn(&str) -> bool) \
+                filter: Some(fn(&str) -> Result<(), String>), \
+                value_parser: None, \
+                possible_values: None, \
+                case_insensitive: false, \
+                multiple: false, \
+                min_values: None, \
+                max_values: None \
             }";
         let actual = format!("{:?}", argument);
 
@@ -2033,12 +4970,138 @@ mod tests {
     #[test]
     fn argument_fmt_missing_options() {
         let argument = Argument::new("argument").unwrap();
-        let expected = "Argument { title: \"argument\", description: None, filter: None }";
+        let expected = "Argument { \
+                title: \"argument\", \
+                description: None, \
+                filter: None, \
+                value_parser: None, \
+                possible_values: None, \
+                case_insensitive: false, \
+                multiple: false, \
+                min_values: None, \
+                max_values: None \
+            }";
+        let actual = format!("{:?}", argument);
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Argument::value_parser must correctly set the value parser callback.
+    ///
+    /// The value_parser method must correctly set the internal Argument
+    /// value_parser callback when passed a closure.
+    #[test]
+    fn argument_value_parser() {
+        let argument = Argument::new("my_argument")
+            .unwrap()
+            .value_parser(|value: &str| value.parse::<u32>().map_err(|error| error.to_string()));
+
+        assert!(argument.value_parser.is_some());
+    }
+
+    /// Argument::fmt must debug a set value parser.
+    ///
+    /// The custom implementation of the Debug::fmt method must correctly
+    /// display an Argument with a value_parser set.
+    #[test]
+    fn argument_fmt_value_parser() {
+        let argument = Argument::new("argument")
+            .unwrap()
+            .value_parser(|value: &str| value.parse::<u32>().map_err(|error| error.to_string()));
+        let expected = "Argument { \
+                title: \"argument\", \
+                description: None, \
+                filter: None, \
+                value_parser: Some(fn(&str) -> Result<_, String>), \
+                possible_values: None, \
+                case_insensitive: false, \
+                multiple: false, \
+                min_values: None, \
+                max_values: None \
+            }";
         let actual = format!("{:?}", argument);
 
         assert_eq!(expected, actual);
     }
 
+    /// Argument::possible_values must correctly set the possible values.
+    ///
+    /// The possible_values method must correctly set the internal Argument
+    /// possible_values to the provided set, with no descriptions.
+    #[test]
+    fn argument_possible_values() {
+        let argument = Argument::new("my_argument")
+            .unwrap()
+            .possible_values(&["add", "remove"]);
+
+        assert_eq!(
+            Some(vec![
+                (String::from("add"), None),
+                (String::from("remove"), None)
+            ]),
+            argument.possible_values
+        );
+    }
+
+    /// Argument::possible_value must correctly accumulate described values.
+    ///
+    /// The possible_value method must append described values to the
+    /// internal Argument possible_values across repeated calls.
+    #[test]
+    fn argument_possible_value() {
+        let argument = Argument::new("my_argument")
+            .unwrap()
+            .possible_value("add", "Add an item")
+            .possible_value("remove", "Remove an item");
+
+        assert_eq!(
+            Some(vec![
+                (String::from("add"), Some(String::from("Add an item"))),
+                (String::from("remove"), Some(String::from("Remove an item"))),
+            ]),
+            argument.possible_values
+        );
+    }
+
+    /// Argument::multiple must correctly set the multiple flag.
+    ///
+    /// The multiple method must set whether this Argument collects one or
+    /// more trailing values rather than exactly one.
+    #[test]
+    fn argument_multiple() {
+        let argument = Argument::new("my_argument").unwrap().multiple(true);
+
+        assert!(argument.multiple);
+    }
+
+    /// Argument::min_values must correctly set the minimum value count.
+    ///
+    /// The min_values method must set the internal Argument min_values to
+    /// the provided count.
+    #[test]
+    fn argument_min_values() {
+        let argument = Argument::new("my_argument")
+            .unwrap()
+            .multiple(true)
+            .min_values(2);
+
+        assert_eq!(Some(2), argument.min_values);
+    }
+
+    /// Argument::max_values must correctly set the maximum value count.
+    ///
+    /// The max_values method must set the internal Argument max_values to
+    /// the provided count.
+    #[test]
+    fn argument_max_values() {
+        let argument = Argument::new("my_argument")
+            .unwrap()
+            .multiple(true)
+            .max_values(3);
+
+        assert_eq!(Some(3), argument.max_values);
+    }
+
     /// Field::new must create as per struct initialisation.
     ///
     /// The new method on Field must create an object as per the struct
@@ -2051,6 +5114,17 @@ mod tests {
             short: None,
             default: None,
             filter: None,
+            value_parser: None,
+            possible_values: None,
+            case_insensitive: false,
+            kind: FieldType::String,
+            required: false,
+            multiple: false,
+            min_values: None,
+            max_values: None,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            required_unless: None,
         };
         let actual = Field::new("Title").unwrap();
 
@@ -2104,6 +5178,149 @@ mod tests {
         assert_eq!(Some(String::from("My default.")), field.default);
     }
 
+    /// Field::kind must correctly set the kind.
+    ///
+    /// The kind method must correctly set the internal Field kind to the
+    /// provided FieldType.
+    #[test]
+    fn field_kind() {
+        let field = Field::new("my_field").unwrap().kind(FieldType::Integer);
+
+        assert_eq!(FieldType::Integer, field.kind);
+    }
+
+    /// Field::required must correctly set required.
+    ///
+    /// The required method must correctly set the internal Field required
+    /// flag to true.
+    #[test]
+    fn field_required() {
+        let field = Field::new("my_field").unwrap().required();
+
+        assert!(field.required);
+    }
+
+    /// Field::multiple must correctly set the multiple flag.
+    ///
+    /// The multiple method must set whether this Field collects every
+    /// occurrence given during parsing or the final one overwrites the rest.
+    #[test]
+    fn field_multiple() {
+        let field = Field::new("my_field").unwrap().multiple(true);
+
+        assert!(field.multiple);
+    }
+
+    /// Field::min_values must correctly set min_values.
+    ///
+    /// The min_values method must correctly set the internal Field
+    /// min_values to the provided count.
+    #[test]
+    fn field_min_values() {
+        let field = Field::new("my_field").unwrap().multiple(true).min_values(2);
+
+        assert_eq!(Some(2), field.min_values);
+    }
+
+    /// Field::max_values must correctly set max_values.
+    ///
+    /// The max_values method must correctly set the internal Field
+    /// max_values to the provided count.
+    #[test]
+    fn field_max_values() {
+        let field = Field::new("my_field").unwrap().multiple(true).max_values(3);
+
+        assert_eq!(Some(3), field.max_values);
+    }
+
+    /// Value::parse must parse a String verbatim.
+    ///
+    /// The parse method must wrap the raw value in a Value::String without
+    /// any conversion when given FieldType::String.
+    #[test]
+    fn value_parse_string() {
+        let expected = Ok(Value::String(String::from("hello")));
+        let actual = Value::parse(FieldType::String, "hello");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must parse an Integer.
+    ///
+    /// The parse method must parse a full valid token as a Value::Integer
+    /// when given FieldType::Integer.
+    #[test]
+    fn value_parse_integer() {
+        let expected = Ok(Value::Integer(-10));
+        let actual = Value::parse(FieldType::Integer, "-10");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must reject trailing garbage on an Integer.
+    ///
+    /// The parse method must fail strict full-token parsing when an Integer
+    /// token is followed by non-numeric characters.
+    #[test]
+    fn value_parse_integer_trailing_garbage() {
+        let expected = Err("an integer");
+        let actual = Value::parse(FieldType::Integer, "10abc");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must reject an empty Integer token.
+    ///
+    /// The parse method must fail when given an empty token for
+    /// FieldType::Integer.
+    #[test]
+    fn value_parse_integer_empty() {
+        let expected = Err("an integer");
+        let actual = Value::parse(FieldType::Integer, "");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must parse a Float.
+    ///
+    /// The parse method must parse a full valid token as a Value::Float when
+    /// given FieldType::Float.
+    #[test]
+    fn value_parse_float() {
+        let expected = Ok(Value::Float(1.5));
+        let actual = Value::parse(FieldType::Float, "1.5");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must reject a non numeric Float token.
+    ///
+    /// The parse method must fail when given a token that cannot be parsed as
+    /// a float for FieldType::Float.
+    #[test]
+    fn value_parse_float_invalid() {
+        let expected = Err("a float");
+        let actual = Value::parse(FieldType::Float, "abc");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must parse a Bool.
+    ///
+    /// The parse method must parse the literal tokens "true" and "false" as a
+    /// Value::Bool when given FieldType::Bool.
+    #[test]
+    fn value_parse_bool() {
+        let expected = Ok(Value::Bool(true));
+        let actual = Value::parse(FieldType::Bool, "true");
+        assert_eq!(expected, actual);
+    }
+
+    /// Value::parse must reject a non boolean token.
+    ///
+    /// The parse method must fail strict parsing when given a token other
+    /// than the literal "true" or "false" for FieldType::Bool.
+    #[test]
+    fn value_parse_bool_invalid() {
+        let expected = Err("a boolean");
+        let actual = Value::parse(FieldType::Bool, "TRUE");
+        assert_eq!(expected, actual);
+    }
+
     /// Field::filter must correctly set the filter callback with a closure.
     ///
     /// The filter method must correctly set the internal Field filter callback when
@@ -2117,6 +5334,26 @@ mod tests {
         assert!(field.filter.is_some());
     }
 
+    /// Field::filter must correctly set the filter callback with a
+    /// reason-returning closure.
+    ///
+    /// The filter method must correctly set the internal Field filter
+    /// callback when passed a closure returning `Result<(), String>`.
+    #[test]
+    fn field_filter_closure_with_reason() {
+        let field = Field::new("username").unwrap().filter(
+            |value: &str| -> Result<(), String> {
+                if value.chars().all(char::is_alphanumeric) {
+                    Ok(())
+                } else {
+                    Err(String::from("must be alphanumeric"))
+                }
+            },
+        );
+
+        assert!(field.filter.is_some());
+    }
+
     /// Field::filter must correctly set the filter callback with a method.
     ///
     /// The filter method must correctly set the internal Field filter callback when
@@ -2148,7 +5385,18 @@ mod tests {
                 description: Some(\"Field description.\"), \
                 short: Some('f'), \
                 default: Some(\"value\"), \
-                filter: Some(fn(&str) -> bool) \
+                filter: Some(fn(&str) -> Result<(), String>), \
+                value_parser: None, \
+                possible_values: None, \
+                case_insensitive: false, \
+                kind: String, \
+                required: false, \
+                multiple: false, \
+                min_values: None, \
+                max_values: None, \
+                conflicts: [], \
+                requires: [], \
+                required_unless: None \
             }";
         let actual = format!("{:?}", field);
 
@@ -2167,13 +5415,106 @@ mod tests {
             description: None, \
             short: None, \
             default: None, \
-            filter: None \
+            filter: None, \
+            value_parser: None, \
+            possible_values: None, \
+            case_insensitive: false, \
+            kind: String, \
+            required: false, \
+            multiple: false, \
+            min_values: None, \
+            max_values: None, \
+            conflicts: [], \
+            requires: [], \
+            required_unless: None \
         }";
         let actual = format!("{:?}", field);
 
         assert_eq!(expected, actual);
     }
 
+    /// Field::value_parser must correctly set the value parser callback.
+    ///
+    /// The value_parser method must correctly set the internal Field
+    /// value_parser callback when passed a closure.
+    #[test]
+    fn field_value_parser() {
+        let field = Field::new("port")
+            .unwrap()
+            .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string()));
+
+        assert!(field.value_parser.is_some());
+    }
+
+    /// Field::fmt must debug a set value parser.
+    ///
+    /// The custom implementation of the Debug::fmt method must correctly
+    /// display a Field with a value_parser set.
+    #[test]
+    fn field_fmt_value_parser() {
+        let field = Field::new("port")
+            .unwrap()
+            .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string()));
+        let expected = "Field { \
+                title: \"port\", \
+                description: None, \
+                short: None, \
+                default: None, \
+                filter: None, \
+                value_parser: Some(fn(&str) -> Result<_, String>), \
+                possible_values: None, \
+                case_insensitive: false, \
+                kind: String, \
+                required: false, \
+                multiple: false, \
+                min_values: None, \
+                max_values: None, \
+                conflicts: [], \
+                requires: [], \
+                required_unless: None \
+            }";
+        let actual = format!("{:?}", field);
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Field::possible_values must correctly set the possible values.
+    ///
+    /// The possible_values method must correctly set the internal Field
+    /// possible_values to the provided set, with no descriptions.
+    #[test]
+    fn field_possible_values() {
+        let field = Field::new("mode").unwrap().possible_values(&["add", "remove"]);
+
+        assert_eq!(
+            Some(vec![
+                (String::from("add"), None),
+                (String::from("remove"), None)
+            ]),
+            field.possible_values
+        );
+    }
+
+    /// Field::possible_value must correctly accumulate described values.
+    ///
+    /// The possible_value method must append described values to the
+    /// internal Field possible_values across repeated calls.
+    #[test]
+    fn field_possible_value() {
+        let field = Field::new("mode")
+            .unwrap()
+            .possible_value("add", "Add an item")
+            .possible_value("remove", "Remove an item");
+
+        assert_eq!(
+            Some(vec![
+                (String::from("add"), Some(String::from("Add an item"))),
+                (String::from("remove"), Some(String::from("Remove an item"))),
+            ]),
+            field.possible_values
+        );
+    }
+
     /// Flag::new must create as per struct initialisation.
     ///
     /// The new method on Flag must create an object as per the struct
@@ -2184,6 +5525,11 @@ mod tests {
             title: String::from("verbose"),
             description: None,
             short: None,
+            required: false,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            required_unless: None,
+            count: false,
         };
         let actual = Flag::new("verbose").unwrap();
 
@@ -2235,7 +5581,12 @@ mod tests {
             action: &action,
             arguments: Vec::new(),
             fields: HashMap::new(),
+            field_sources: HashMap::new(),
+            field_values: HashMap::new(),
             flags: HashMap::new(),
+            flag_counts: HashMap::new(),
+            parsed_arguments: Vec::new(),
+            parsed_fields: HashMap::new(),
         };
         let actual = Request::new(&action);
 
@@ -2263,17 +5614,25 @@ mod tests {
         fields.insert(String::from("my_field"), None);
         fields.insert(
             String::from("my_field_default"),
-            Some(String::from("default")),
+            Some(Value::String(String::from("default"))),
         );
 
         let mut flags = HashMap::new();
         flags.insert(String::from("my_flag"), false);
 
+        let mut field_sources = HashMap::new();
+        field_sources.insert(String::from("my_field_default"), ValueSource::Default);
+
         let expected = Request {
             action: &action,
             arguments: Vec::new(),
             fields,
+            field_sources,
+            field_values: HashMap::new(),
             flags,
+            flag_counts: HashMap::new(),
+            parsed_arguments: Vec::new(),
+            parsed_fields: HashMap::new(),
         };
         let actual = Request::new(&action);
 
@@ -2360,27 +5719,83 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
-    /// Request::get_flag must retrieve the Flag.
+    /// Request::field_source must return None if the Field was never
+    /// supplied and carries no default.
     ///
-    /// The get flag method must retrieve the Flag at the index.
+    /// The field source method must retrieve None if the Field has no
+    /// value.
     #[test]
-    fn request_get_flag() {
-        let expected = Some(&true);
+    fn request_field_source_absent() {
+        let expected = None;
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_field(Field::new("my_field").unwrap())
             .unwrap();
-        let request = Request::new(&action).insert_flag("my_flag").unwrap();
-        let actual = request.get_flag("my_flag");
+        let request = Request::new(&action);
+        let actual = request.field_source("my_field");
 
         assert_eq!(expected, actual);
     }
 
-    /// Request::get_flag must return None if the Flag does not exist.
+    /// Request::field_source must return Default if the Field was never
+    /// supplied but carries a default.
     ///
-    /// The get flag method must retrieve None if the Flag does not exist.
+    /// The field source method must retrieve Default if the Field fell
+    /// back to its configured default.
     #[test]
-    fn request_get_flag_not_exists() {
+    fn request_field_source_default() {
+        let expected = Some(ValueSource::Default);
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap().default("fallback"))
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.field_source("my_field");
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::field_source must return CommandLine if the Field was
+    /// supplied on the command line.
+    ///
+    /// The field source method must retrieve CommandLine if the Field was
+    /// inserted, even if it also carries a default.
+    #[test]
+    fn request_field_source_command_line() {
+        let expected = Some(ValueSource::CommandLine);
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap().default("fallback"))
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_field("my_field", "value")
+            .unwrap();
+        let actual = request.field_source("my_field");
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::get_flag must retrieve the Flag.
+    ///
+    /// The get flag method must retrieve the Flag at the index.
+    #[test]
+    fn request_get_flag() {
+        let expected = Some(&true);
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap();
+        let request = Request::new(&action).insert_flag("my_flag").unwrap();
+        let actual = request.get_flag("my_flag");
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::get_flag must return None if the Flag does not exist.
+    ///
+    /// The get flag method must retrieve None if the Flag does not exist.
+    #[test]
+    fn request_get_flag_not_exists() {
         let expected = None;
         let action = Action::<()>::new("my_action").unwrap();
         let request = Request::new(&action);
@@ -2389,355 +5804,1605 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
-    /// Request::has_argument must return if the Argument exists.
+    /// Request::get_flag_count must accumulate each occurrence of a `count`
+    /// Flag.
+    ///
+    /// The get_flag_count method must return the number of times a `count`
+    /// Flag was supplied.
+    #[test]
+    fn request_get_flag_count_accumulates() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap().count())
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_flag("verbose")
+            .unwrap()
+            .insert_flag("verbose")
+            .unwrap()
+            .insert_flag("verbose")
+            .unwrap();
+
+        assert_eq!(3, request.get_flag_count("verbose"));
+    }
+
+    /// Request::get_flag_count must default a non-`count` Flag to 0 or 1.
+    ///
+    /// The get_flag_count method must report 0 for an absent, non-`count`
+    /// Flag and 1 for a present one, regardless of how many times it was
+    /// supplied.
+    #[test]
+    fn request_get_flag_count_non_counting_defaults() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap())
+            .unwrap();
+        let absent = Request::new(&action);
+        let present = Request::new(&action).insert_flag("verbose").unwrap();
+
+        assert_eq!(0, absent.get_flag_count("verbose"));
+        assert_eq!(1, present.get_flag_count("verbose"));
+    }
+
+    /// Request::get_flag_count must return 0 for an unknown title.
+    ///
+    /// The get_flag_count method must default to 0 when the title is not
+    /// registered on the Action at all.
+    #[test]
+    fn request_get_flag_count_not_exists() {
+        let action = Action::<()>::new("my_action").unwrap();
+        let request = Request::new(&action);
+
+        assert_eq!(0, request.get_flag_count("verbose"));
+    }
+
+    /// Request::has_argument must return if the Argument exists.
+    ///
+    /// The has argument method must return true if the Argument exists.
+    #[test]
+    fn request_has_argument() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+        let request = Request::new(&action).insert_argument("value").unwrap();
+        let actual = request.has_argument(0);
+        assert!(actual);
+    }
+
+    /// Request::has_argument must return if the Argument does not exist.
+    ///
+    /// The has argument method must return false if the Argument does not exist.
+    #[test]
+    fn request_has_argument_false() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+        let request = Request::new(&action).insert_argument("value").unwrap();
+        let actual = request.has_argument(1);
+        assert!(!actual);
+    }
+
+    /// Request::has_field must return if the Field exists.
+    ///
+    /// The has field method must return true if the Field exists.
+    #[test]
+    fn request_has_field() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap())
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_field("my_field");
+        assert!(actual);
+    }
+
+    /// Request::has_field must return if the Field exists using the short tag.
+    ///
+    /// The has field method must return true if the Field exists when provieded
+    /// with the short tag.
+    #[test]
+    fn request_has_field_short() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap().short('m'))
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_field("m");
+        assert!(actual);
+    }
+
+    /// Request::has_field must return if the Field does not exist.
+    ///
+    /// The has field method must return false if the Field does not exist.
+    #[test]
+    fn request_has_field_false() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap())
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_field("not_my_field");
+        assert!(!actual);
+    }
+
+    /// Request::has_flag must return if the Flag exists.
+    ///
+    /// The has flag method must return true if the Flag exists.
+    #[test]
+    fn request_has_flag() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_flag("my_flag");
+        assert!(actual);
+    }
+
+    /// Request::has_flag must return if the Flag exists using the short tag.
+    ///
+    /// The has flag method must return true if the Flag exists when provieded
+    /// with the short tag.
+    #[test]
+    fn request_has_flag_short() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap().short('m'))
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_flag("m");
+        assert!(actual);
+    }
+
+    /// Request::has_flag must return if the Flag does not exist.
+    ///
+    /// The has flag method must return false if the Flag does not exist.
+    #[test]
+    fn request_has_flag_false() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap();
+        let request = Request::new(&action);
+        let actual = request.has_flag("not_my_flag");
+        assert!(!actual);
+    }
+
+    /// Request::insert_argument must insert an Argument.
+    ///
+    /// The insert argument method must insert an Argument into the Request.
+    #[test]
+    fn request_insert_argument() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+
+        let mut expected = Request::new(&action);
+        expected.arguments.push(String::from("value"));
+
+        let actual = Request::new(&action).insert_argument("value").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must insert if the filter is passed.
+    ///
+    /// The insert argument method must insert an Argument into the Request if the
+    /// filter callback passess successfully.
+    #[test]
+    fn request_insert_argument_filter_pass() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("my_argument")
+                    .unwrap()
+                    .filter(|value| -> bool { value == "value" }),
+            )
+            .unwrap();
+
+        let mut expected = Request::new(&action);
+        expected.arguments.push(String::from("value"));
+
+        let actual = Request::new(&action).insert_argument("value").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must error if the filter fails.
+    ///
+    /// The insert argument method must return an Error if the filter callback
+    /// fails.
+    #[test]
+    fn request_insert_argument_filter_fail() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("my_argument")
+                    .unwrap()
+                    .filter(|value| -> bool { value != "value" }),
+            )
+            .unwrap();
+
+        let expected = Error::invalid_value("my_argument", "value", "");
+        let actual = Request::new(&action).insert_argument("value").unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must surface the filter's rejection reason.
+    ///
+    /// The insert argument method must return an InvalidValue Error carrying
+    /// the message returned by a `Result<(), String>` filter callback.
+    #[test]
+    fn request_insert_argument_filter_fail_with_reason() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap().filter(
+                |value: &str| -> Result<(), String> {
+                    if value.chars().all(char::is_alphanumeric) {
+                        Ok(())
+                    } else {
+                        Err(String::from("must be alphanumeric"))
+                    }
+                },
+            ))
+            .unwrap();
+
+        let expected = Error::invalid_value("my_argument", "not valid!", "must be alphanumeric");
+        let actual = Request::new(&action)
+            .insert_argument("not valid!")
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must error if trying to insert too many Arguments.
+    ///
+    /// The insert argument method must return an Error if attempting to insert too
+    /// many Arguments for the Action.
+    #[test]
+    fn request_insert_argument_overflow() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+
+        let expected = Error::new("Action 'my_action' does not accept any more arguments.");
+        let actual = Request::new(&action)
+            .insert_argument("value")
+            .unwrap()
+            .insert_argument("value")
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must run the value parser.
+    ///
+    /// The insert argument method must run the Argument's value_parser, if
+    /// any, and store the parsed value for later retrieval via
+    /// `Request::argument_as`.
+    #[test]
+    fn request_insert_argument_value_parser() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("count")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u32>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let request = Request::new(&action).insert_argument("5").unwrap();
+
+        assert_eq!(5_u32, request.argument_as::<u32>(0).unwrap());
+    }
+
+    /// Request::insert_argument must error if the value parser fails.
+    ///
+    /// The insert argument method must return an InvalidValue Error if the
+    /// Argument's value_parser rejects the raw value.
+    #[test]
+    fn request_insert_argument_value_parser_fail() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("count")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u32>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let actual = Request::new(&action).insert_argument("abc").unwrap_err();
+
+        assert!(matches!(actual, Error::InvalidValue { .. }));
+    }
+
+    /// Request::argument_as must error if the Argument has no parsed value.
+    ///
+    /// The argument_as method must return a MissingArgument Error if the
+    /// Argument at the given index has not been inserted.
+    #[test]
+    fn request_argument_as_missing() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("count")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u32>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let expected = Error::missing_argument("my_action", "count");
+        let actual = Request::new(&action).argument_as::<u32>(0).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must accept a value in the possible set.
+    ///
+    /// The insert argument method must insert an Argument value that is a
+    /// member of its possible_values set.
+    #[test]
+    fn request_insert_argument_possible_values_pass() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("mode").unwrap().possible_values(&["add", "remove"]))
+            .unwrap();
+
+        let request = Request::new(&action).insert_argument("add").unwrap();
+
+        assert_eq!(Some(&String::from("add")), request.get_argument(0));
+    }
+
+    /// Request::insert_argument must error on a value outside the possible set.
+    ///
+    /// The insert argument method must return an InvalidChoice Error when the
+    /// Argument value is not a member of its possible_values set.
+    #[test]
+    fn request_insert_argument_possible_values_fail() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("mode").unwrap().possible_values(&["add", "remove"]))
+            .unwrap();
+
+        let expected = Error::invalid_choice(
+            "mode",
+            "update",
+            &[String::from("add"), String::from("remove")],
+        );
+        let actual = Request::new(&action).insert_argument("update").unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_argument must accept a value matching possible_values
+    /// only by case when case_insensitive is set.
+    ///
+    /// The insert argument method must succeed when the supplied value
+    /// matches a possible_values entry in a different case and
+    /// case_insensitive is set.
+    #[test]
+    fn request_insert_argument_possible_values_case_insensitive() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(
+                Argument::new("mode")
+                    .unwrap()
+                    .possible_values(&["add", "remove"])
+                    .case_insensitive(),
+            )
+            .unwrap();
+
+        let request = Request::new(&action).insert_argument("ADD").unwrap();
+
+        assert_eq!(Some(&String::from("ADD")), request.get_argument(0));
+    }
+
+    /// Request::insert_argument must collect several values for a `multiple`
+    /// Argument.
+    ///
+    /// The insert argument method must accept repeated values for an
+    /// Argument marked `multiple` instead of erroring on overflow.
+    #[test]
+    fn request_insert_argument_multiple() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("files").unwrap().multiple(true))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_argument("a")
+            .unwrap()
+            .insert_argument("b")
+            .unwrap()
+            .insert_argument("c")
+            .unwrap();
+
+        assert_eq!(3, request.arguments.len());
+    }
+
+    /// Request::insert_argument must error past a `multiple` Argument's
+    /// max_values.
+    ///
+    /// The insert argument method must return an Error when inserting more
+    /// values than a `multiple` Argument's max_values allows.
+    #[test]
+    fn request_insert_argument_multiple_max_values() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("files").unwrap().multiple(true).max_values(2))
+            .unwrap();
+
+        let expected = Error::new("Argument 'files' accepts at most 2 value(s).");
+        let actual = Request::new(&action)
+            .insert_argument("a")
+            .unwrap()
+            .insert_argument("b")
+            .unwrap()
+            .insert_argument("c")
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::get_argument_values must return every collected value.
+    ///
+    /// The get_argument_values method must return every value collected for
+    /// a `multiple` Argument.
+    #[test]
+    fn request_get_argument_values_multiple() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("files").unwrap().multiple(true))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_argument("a")
+            .unwrap()
+            .insert_argument("b")
+            .unwrap();
+
+        assert_eq!(
+            &[String::from("a"), String::from("b")],
+            request.get_argument_values(0)
+        );
+    }
+
+    /// Request::get_argument_values must return a single-element slice for a
+    /// regular Argument.
+    ///
+    /// The get_argument_values method must return a slice of at most one
+    /// element for an Argument that is not `multiple`.
+    #[test]
+    fn request_get_argument_values_single() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+
+        let request = Request::new(&action).insert_argument("value").unwrap();
+
+        assert_eq!(&[String::from("value")], request.get_argument_values(0));
+    }
+
+    /// Request::insert_field must insert a Field.
+    ///
+    /// The insert field method must insert a Field into the Request.
+    #[test]
+    fn request_insert_field() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap())
+            .unwrap();
+
+        let mut expected = Request::new(&action);
+        expected.fields.insert(
+            String::from("my_field"),
+            Some(Value::String(String::from("value"))),
+        );
+        expected
+            .field_sources
+            .insert(String::from("my_field"), ValueSource::CommandLine);
+
+        let actual = Request::new(&action)
+            .insert_field("my_field", "value")
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must error if Field is not found.
+    ///
+    /// The insert field method must error if the Field does not exist on the Action.
+    #[test]
+    fn request_insert_field_not_found() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("my_field").unwrap())
+            .unwrap();
+
+        let expected = Error::new("Unknown field 'not_my_field' for action 'my_action'.");
+        let actual = Request::new(&action)
+            .insert_field("not_my_field", "value")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must error if Field filter fails.
+    ///
+    /// The insert field method must error if the Field's valdiation filter fails.
+    #[test]
+    fn request_insert_field_fail_filter() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("my_field")
+                    .unwrap()
+                    .filter(|_| -> bool { false }),
+            )
+            .unwrap();
+
+        let expected = Error::invalid_value("my_field", "value", "");
+        let actual = Request::new(&action)
+            .insert_field("my_field", "value")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must surface the filter's rejection reason.
+    ///
+    /// The insert field method must return an InvalidValue Error carrying the
+    /// message returned by a `Result<(), String>` filter callback.
+    #[test]
+    fn request_insert_field_fail_filter_with_reason() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("username").unwrap().filter(
+                |value: &str| -> Result<(), String> {
+                    if value.chars().all(char::is_alphanumeric) {
+                        Ok(())
+                    } else {
+                        Err(String::from("must be alphanumeric"))
+                    }
+                },
+            ))
+            .unwrap();
+
+        let expected = Error::invalid_value("username", "not valid!", "must be alphanumeric");
+        let actual = Request::new(&action)
+            .insert_field("username", "not valid!")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must parse the value according to the Field's kind.
+    ///
+    /// The insert field method must store a typed Value, parsed according to
+    /// the Field's declared FieldType.
+    #[test]
+    fn request_insert_field_typed() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("port").unwrap().kind(FieldType::Integer))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        assert_eq!(Some(8080_i64), request.field_as("port").ok());
+    }
+
+    /// Request::insert_field must error if the value does not match the kind.
+    ///
+    /// The insert field method must return an InvalidFieldValue Error if the
+    /// raw value cannot be parsed as the Field's declared FieldType.
+    #[test]
+    fn request_insert_field_invalid_value() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("port").unwrap().kind(FieldType::Integer))
+            .unwrap();
+
+        let expected = Error::invalid_field_value("port", "abc", "an integer");
+        let actual = Request::new(&action)
+            .insert_field("port", "abc")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must check the Field's kind before its filter.
+    ///
+    /// The insert field method must report an InvalidFieldValue Error for a
+    /// value that fails to parse as the Field's declared FieldType, even if
+    /// the Field also has a filter that would otherwise reject the value.
+    #[test]
+    fn request_insert_field_invalid_value_before_filter() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .kind(FieldType::Integer)
+                    .filter(|_| -> bool { false }),
+            )
+            .unwrap();
+
+        let expected = Error::invalid_field_value("port", "abc", "an integer");
+        let actual = Request::new(&action)
+            .insert_field("port", "abc")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::field_as must retrieve a typed Field value.
+    ///
+    /// The field_as method must retrieve a Field value, converted to the
+    /// requested type.
+    #[test]
+    fn request_field_as() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("verbose").unwrap().kind(FieldType::Bool))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("verbose", "true")
+            .unwrap();
+
+        assert_eq!(true, request.field_as::<bool>("verbose").unwrap());
+    }
+
+    /// Request::field_as must error if the Field has no value.
+    ///
+    /// The field_as method must return a MissingFieldValue Error if the Field
+    /// has no value and no default.
+    #[test]
+    fn request_field_as_missing() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("port").unwrap().kind(FieldType::Integer))
+            .unwrap();
+
+        let expected = Error::missing_field_value("port");
+        let actual = Request::new(&action).field_as::<i64>("port").unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::field_as must error if the stored Value is the wrong type.
+    ///
+    /// The field_as method must error if the Field's stored Value does not
+    /// hold the requested type.
+    #[test]
+    fn request_field_as_wrong_type() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("port").unwrap().kind(FieldType::Integer))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        assert!(request.field_as::<String>("port").is_err());
+    }
+
+    /// Request::insert_field must accept a value in the possible set.
+    ///
+    /// The insert field method must insert a Field value that is a member of
+    /// its possible_values set.
+    #[test]
+    fn request_insert_field_possible_values_pass() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("mode").unwrap().possible_values(&["add", "remove"]))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("mode", "add")
+            .unwrap();
+
+        assert_eq!(Some(&String::from("add")), request.get_field("mode"));
+    }
+
+    /// Request::insert_field must error on a value outside the possible set.
+    ///
+    /// The insert field method must return an InvalidChoice Error when the
+    /// Field value is not a member of its possible_values set.
+    #[test]
+    fn request_insert_field_possible_values_fail() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("mode").unwrap().possible_values(&["add", "remove"]))
+            .unwrap();
+
+        let expected = Error::invalid_choice(
+            "mode",
+            "update",
+            &[String::from("add"), String::from("remove")],
+        );
+        let actual = Request::new(&action)
+            .insert_field("mode", "update")
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_field must accept a value matching possible_values
+    /// only by case when case_insensitive is set.
+    ///
+    /// The insert field method must succeed when the supplied value matches
+    /// a possible_values entry in a different case and case_insensitive is
+    /// set.
+    #[test]
+    fn request_insert_field_possible_values_case_insensitive() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("mode")
+                    .unwrap()
+                    .possible_values(&["add", "remove"])
+                    .case_insensitive(),
+            )
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("mode", "ADD")
+            .unwrap();
+
+        assert_eq!(Some(&String::from("ADD")), request.get_field("mode"));
+    }
+
+    /// Request::insert_field must collect every occurrence of a `multiple`
+    /// Field.
+    ///
+    /// The insert field method must accumulate each occurrence of a
+    /// `multiple` Field into a list instead of overwriting the previous
+    /// value.
+    #[test]
+    fn request_insert_field_multiple() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("tag").unwrap().multiple(true))
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("tag", "a")
+            .unwrap()
+            .insert_field("tag", "b")
+            .unwrap();
+
+        assert_eq!(
+            &[String::from("a"), String::from("b")],
+            request.get_fields("tag").unwrap()
+        );
+    }
+
+    /// Request::insert_field must error past a `multiple` Field's
+    /// max_values.
+    ///
+    /// The insert field method must return an Error when inserting more
+    /// occurrences than a `multiple` Field's max_values allows.
+    #[test]
+    fn request_insert_field_multiple_max_values() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("tag").unwrap().multiple(true).max_values(2))
+            .unwrap();
+
+        let expected = Error::new("Field 'tag' accepts at most 2 value(s).");
+        let actual = Request::new(&action)
+            .insert_field("tag", "a")
+            .unwrap()
+            .insert_field("tag", "b")
+            .unwrap()
+            .insert_field("tag", "c")
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::get_fields must return None for a Field that is not
+    /// `multiple`.
+    ///
+    /// The get_fields method must return None when the named Field was never
+    /// marked `Field::multiple`, even if it was given a value.
+    #[test]
+    fn request_get_fields_not_multiple() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("tag").unwrap())
+            .unwrap();
+
+        let request = Request::new(&action).insert_field("tag", "a").unwrap();
+
+        assert_eq!(None, request.get_fields("tag"));
+    }
+
+    /// Request::get_fields must fall back to the Field's default.
+    ///
+    /// The get_fields method must return a single-element slice holding the
+    /// `default` when a `multiple` Field was never supplied a value.
+    #[test]
+    fn request_get_fields_default() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("tag").unwrap().multiple(true).default("latest"))
+            .unwrap();
+
+        let request = Request::new(&action);
+
+        assert_eq!(&[String::from("latest")], request.get_fields("tag").unwrap());
+    }
+
+    /// Request::get_fields must prefer supplied values over the default.
+    ///
+    /// The get_fields method must not mix the `default` in once the Field
+    /// has actually been supplied a value.
+    #[test]
+    fn request_get_fields_supplied_overrides_default() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(Field::new("tag").unwrap().multiple(true).default("latest"))
+            .unwrap();
+
+        let request = Request::new(&action).insert_field("tag", "a").unwrap();
+
+        assert_eq!(&[String::from("a")], request.get_fields("tag").unwrap());
+    }
+
+    /// Request::insert_field must run the value parser.
+    ///
+    /// The insert field method must run the Field's value_parser, if any,
+    /// and store the parsed value for later retrieval via
+    /// `Request::field_parsed`.
+    #[test]
+    fn request_insert_field_value_parser() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        assert_eq!(8080_u16, request.field_parsed::<u16>("port").unwrap());
+    }
+
+    /// Request::insert_field must error if the value parser fails.
+    ///
+    /// The insert field method must return an InvalidValue Error if the
+    /// Field's value_parser rejects the raw value.
+    #[test]
+    fn request_insert_field_value_parser_fail() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let actual = Request::new(&action)
+            .insert_field("port", "not_a_port")
+            .unwrap_err();
+
+        assert!(matches!(actual, Error::InvalidValue { .. }));
+    }
+
+    /// Request::field_parsed must error if the Field has no parsed value.
+    ///
+    /// The field_parsed method must return a MissingFieldValue Error if the
+    /// Field has not been inserted.
+    #[test]
+    fn request_field_parsed_missing() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let expected = Error::missing_field_value("port");
+        let actual = Request::new(&action).field_parsed::<u16>("port").unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::field_parsed must error if the stored value is the wrong type.
+    ///
+    /// The field_parsed method must error if the Field's parsed value does
+    /// not hold the requested type.
+    #[test]
+    fn request_field_parsed_wrong_type() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        assert!(request.field_parsed::<String>("port").is_err());
+    }
+
+    /// Request::get_field_parsed must return None if the Field has no parsed
+    /// value.
+    ///
+    /// The get_field_parsed method must return Ok(None) rather than an Error
+    /// if the Field has not been inserted.
+    #[test]
+    fn request_get_field_parsed_missing() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let actual = Request::new(&action).get_field_parsed::<u16>("port").unwrap();
+
+        assert_eq!(None, actual);
+    }
+
+    /// Request::get_field_parsed must return the parsed value if supplied.
+    ///
+    /// The get_field_parsed method must return Ok(Some(value)) if the Field
+    /// was supplied and parsed successfully.
+    #[test]
+    fn request_get_field_parsed_present() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        let actual = request.get_field_parsed::<u16>("port").unwrap();
+
+        assert_eq!(Some(8080), actual);
+    }
+
+    /// Request::get_field_parsed must error if the stored value is the wrong
+    /// type.
+    ///
+    /// The get_field_parsed method must error if the Field's parsed value
+    /// does not hold the requested type, even though it was supplied.
+    #[test]
+    fn request_get_field_parsed_wrong_type() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_field(
+                Field::new("port")
+                    .unwrap()
+                    .value_parser(|value: &str| value.parse::<u16>().map_err(|error| error.to_string())),
+            )
+            .unwrap();
+
+        let request = Request::new(&action)
+            .insert_field("port", "8080")
+            .unwrap();
+
+        assert!(request.get_field_parsed::<String>("port").is_err());
+    }
+
+    /// Request::insert_flag must insert a Flag.
+    ///
+    /// The insert flag method must insert a Flag into the Request.
+    #[test]
+    fn request_insert_flag() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap();
+
+        let mut expected = Request::new(&action);
+        expected.flags.insert(String::from("my_flag"), true);
+
+        let actual = Request::new(&action).insert_flag("my_flag").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_flag must error if Flag is not found.
+    ///
+    /// The insert flag method must error if the Flag does not exist on the Action.
+    #[test]
+    fn request_insert_flag_not_found() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("my_flag").unwrap())
+            .unwrap();
+
+        let expected = Error::UnknownFlag {
+            action: String::from("my_action"),
+            token: String::from("not_my_flag"),
+            suggestion: None,
+        };
+        let actual = Request::new(&action)
+            .insert_flag("not_my_flag")
+            .unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::insert_flag must suggest the closest Flag when not found.
+    ///
+    /// The insert_flag method must populate the UnknownFlag Error's
+    /// suggestion with the registered Flag closest to the requested token.
+    #[test]
+    fn request_insert_flag_not_found_suggestion() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap())
+            .unwrap();
+
+        let expected = Error::UnknownFlag {
+            action: String::from("my_action"),
+            token: String::from("verbse"),
+            suggestion: Some(String::from("verbose")),
+        };
+        let actual = Request::new(&action).insert_flag("verbse").unwrap_err();
+        assert_eq!(expected, actual);
+    }
+
+    /// Request::run must run the Action's then callback.
+    ///
+    /// The run method on Request must run the Action's callback it references.
+    #[test]
+    fn request_run() {
+        let action = Action::new("my_action")
+            .unwrap()
+            .then(|_request| -> u8 { 1_u8 });
+        let request = Request::new(&action);
+
+        assert_eq!(1, request.run().unwrap());
+    }
+
+    /// Request::validate must successfully validate the Action.
+    ///
+    /// The validate method on Request must return Ok(()) if the correct number of
+    /// Arguments, Fields and Flags were supplied.
+    #[test]
+    fn request_validate() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+        let request = Request::new(&action).insert_argument("first").unwrap();
+
+        assert!(request.validate().is_ok());
+    }
+
+    /// Request::validate must successfully validate the Action.
+    ///
+    /// The validate method on Request must return Err if too many Arguments were
+    /// supplied.
+    #[test]
+    fn request_validate_argument_overflow() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+        let mut request = Request::new(&action);
+        request.arguments.push(String::from("first"));
+        request.arguments.push(String::from("second"));
+
+        assert!(request.validate().is_err());
+    }
+
+    /// Request::validate must successfully validate the Action.
+    ///
+    /// The validate method on Request must return Err if too few Arguments were
+    /// supplied.
+    #[test]
+    fn request_validate_argument_underflow() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_argument(Argument::new("my_argument").unwrap())
+            .unwrap();
+        let request = Request::new(&action);
+
+        assert!(request.validate().is_err());
+    }
+
+    /// Request::validate must successfully validate a `multiple` Argument.
     ///
-    /// The has argument method must return true if the Argument exists.
+    /// The validate method on Request must return Ok(()) if at least
+    /// min_values values were collected for a `multiple` Argument.
     #[test]
-    fn request_has_argument() {
+    fn request_validate_argument_multiple() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_argument(Argument::new("files").unwrap().multiple(true))
             .unwrap();
-        let request = Request::new(&action).insert_argument("value").unwrap();
-        let actual = request.has_argument(0);
-        assert!(actual);
+        let request = Request::new(&action)
+            .insert_argument("a")
+            .unwrap()
+            .insert_argument("b")
+            .unwrap();
+
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::has_argument must return if the Argument does not exist.
+    /// Request::validate must fail a `multiple` Argument below min_values.
     ///
-    /// The has argument method must return false if the Argument does not exist.
+    /// The validate method on Request must return Err if fewer than
+    /// min_values values were collected for a `multiple` Argument.
     #[test]
-    fn request_has_argument_false() {
+    fn request_validate_argument_multiple_underflow() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_argument(Argument::new("files").unwrap().multiple(true).min_values(2))
             .unwrap();
-        let request = Request::new(&action).insert_argument("value").unwrap();
-        let actual = request.has_argument(1);
-        assert!(!actual);
+        let request = Request::new(&action).insert_argument("a").unwrap();
+
+        assert!(request.validate().is_err());
     }
 
-    /// Request::has_field must return if the Field exists.
+    /// Request::validate must report the min_values bound as the expected
+    /// count for an underflowing `multiple` Argument.
     ///
-    /// The has field method must return true if the Field exists.
+    /// The validate method on Request must report how many Arguments were
+    /// expected (accounting for any preceding fixed Arguments and the
+    /// variadic Argument's min_values) versus how many were received.
     #[test]
-    fn request_has_field() {
+    fn request_validate_argument_multiple_underflow_message() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap())
+            .insert_argument(Argument::new("first").unwrap())
+            .unwrap()
+            .insert_argument(Argument::new("files").unwrap().multiple(true).min_values(2))
             .unwrap();
-        let request = Request::new(&action);
-        let actual = request.has_field("my_field");
-        assert!(actual);
+        let request = Request::new(&action)
+            .insert_argument("a")
+            .unwrap()
+            .insert_argument("b")
+            .unwrap();
+
+        let errors = request.validate().unwrap_err();
+
+        assert!(errors.contains(&String::from("Expected 3 argument(s), found 2.")));
     }
 
-    /// Request::has_field must return if the Field exists using the short tag.
+    /// Request::validate must fail if a required Field is missing a value.
     ///
-    /// The has field method must return true if the Field exists when provieded
-    /// with the short tag.
+    /// The validate method on Request must return Err if a required Field
+    /// was not supplied a value and has no default.
     #[test]
-    fn request_has_field_short() {
+    fn request_validate_required_field_missing() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap().short('m'))
+            .insert_field(Field::new("my_field").unwrap().required())
             .unwrap();
         let request = Request::new(&action);
-        let actual = request.has_field("m");
-        assert!(actual);
+
+        assert!(request.validate().is_err());
     }
 
-    /// Request::has_field must return if the Field does not exist.
+    /// Request::validate must pass if a required Field has a default.
     ///
-    /// The has field method must return false if the Field does not exist.
+    /// The validate method on Request must return Ok(()) if a required Field
+    /// was not supplied a value but has a default populating the Request.
     #[test]
-    fn request_has_field_false() {
+    fn request_validate_required_field_default() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap())
+            .insert_field(Field::new("my_field").unwrap().required().default("value"))
             .unwrap();
         let request = Request::new(&action);
-        let actual = request.has_field("not_my_field");
-        assert!(!actual);
+
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::has_flag must return if the Flag exists.
+    /// Request::validate must pass if a required Field was supplied a value.
     ///
-    /// The has flag method must return true if the Flag exists.
+    /// The validate method on Request must return Ok(()) if a required Field
+    /// was supplied a value during parsing.
     #[test]
-    fn request_has_flag() {
+    fn request_validate_required_field_supplied() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_field(Field::new("my_field").unwrap().required())
             .unwrap();
-        let request = Request::new(&action);
-        let actual = request.has_flag("my_flag");
-        assert!(actual);
+        let request = Request::new(&action)
+            .insert_field("my_field", "value")
+            .unwrap();
+
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::has_flag must return if the Flag exists using the short tag.
+    /// Request::validate must fail if a required, `multiple` Field is under
+    /// its min_values.
     ///
-    /// The has flag method must return true if the Flag exists when provieded
-    /// with the short tag.
+    /// The validate method on Request must return Err if a required,
+    /// `multiple` Field collected fewer occurrences than its min_values.
     #[test]
-    fn request_has_flag_short() {
+    fn request_validate_required_multiple_field_underflow() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap().short('m'))
+            .insert_field(
+                Field::new("tag")
+                    .unwrap()
+                    .required()
+                    .multiple(true)
+                    .min_values(2),
+            )
             .unwrap();
-        let request = Request::new(&action);
-        let actual = request.has_flag("m");
-        assert!(actual);
+        let request = Request::new(&action).insert_field("tag", "a").unwrap();
+
+        assert!(request.validate().is_err());
     }
 
-    /// Request::has_flag must return if the Flag does not exist.
+    /// Request::validate must pass if a required, `multiple` Field meets its
+    /// min_values.
     ///
-    /// The has flag method must return false if the Flag does not exist.
+    /// The validate method on Request must return Ok(()) once a required,
+    /// `multiple` Field has collected at least min_values occurrences.
     #[test]
-    fn request_has_flag_false() {
+    fn request_validate_required_multiple_field_supplied() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_field(
+                Field::new("tag")
+                    .unwrap()
+                    .required()
+                    .multiple(true)
+                    .min_values(2),
+            )
             .unwrap();
-        let request = Request::new(&action);
-        let actual = request.has_flag("not_my_flag");
-        assert!(!actual);
+        let request = Request::new(&action)
+            .insert_field("tag", "a")
+            .unwrap()
+            .insert_field("tag", "b")
+            .unwrap();
+
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::insert_argument must insert an Argument.
+    /// Request::validate must fail if a required Flag is missing.
     ///
-    /// The insert argument method must insert an Argument into the Request.
+    /// The validate method on Request must return Err if a required Flag was
+    /// not supplied.
     #[test]
-    fn request_insert_argument() {
+    fn request_validate_required_flag_missing() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_flag(Flag::new("accept_terms").unwrap().required())
             .unwrap();
+        let request = Request::new(&action);
 
-        let mut expected = Request::new(&action);
-        expected.arguments.push(String::from("value"));
-
-        let actual = Request::new(&action).insert_argument("value").unwrap();
-
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_err());
     }
 
-    /// Request::insert_argument must insert if the filter is passed.
+    /// Request::validate must pass if a required Flag was supplied.
     ///
-    /// The insert argument method must insert an Argument into the Request if the
-    /// filter callback passess successfully.
+    /// The validate method on Request must return Ok(()) if a required Flag
+    /// was supplied.
     #[test]
-    fn request_insert_argument_filter_pass() {
+    fn request_validate_required_flag_supplied() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(
-                Argument::new("my_argument")
-                    .unwrap()
-                    .filter(|value| -> bool { value == "value" }),
-            )
+            .insert_flag(Flag::new("accept_terms").unwrap().required())
             .unwrap();
+        let request = Request::new(&action).insert_flag("accept_terms").unwrap();
 
-        let mut expected = Request::new(&action);
-        expected.arguments.push(String::from("value"));
-
-        let actual = Request::new(&action).insert_argument("value").unwrap();
-
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::insert_argument must error if the filter fails.
+    /// Request::validate must enumerate every unmet requirement at once.
     ///
-    /// The insert argument method must return an Error if the filter callback
-    /// fails.
+    /// The validate method on Request must collect a message for each
+    /// missing required Field and Flag, rather than stopping at the first.
     #[test]
-    fn request_insert_argument_filter_fail() {
+    fn request_validate_collects_every_error() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(
-                Argument::new("my_argument")
-                    .unwrap()
-                    .filter(|value| -> bool { value != "value" }),
-            )
+            .insert_field(Field::new("my_field").unwrap().required())
+            .unwrap()
+            .insert_flag(Flag::new("accept_terms").unwrap().required())
             .unwrap();
+        let request = Request::new(&action);
 
-        let expected = Error::new("Todo: Help.");
-        let actual = Request::new(&action).insert_argument("value").unwrap_err();
-
-        assert_eq!(expected, actual);
+        assert_eq!(2, request.validate().unwrap_err().len());
     }
 
-    /// Request::insert_argument must error if trying to insert too many Arguments.
+    /// Request::validate must fail if a `required_unless` Field is missing
+    /// and its sibling is absent.
     ///
-    /// The insert argument method must return an Error if attempting to insert too
-    /// many Arguments for the Action.
+    /// The validate method on Request must return Err if a Field marked
+    /// `required_unless` was not supplied and the named sibling is also
+    /// absent.
     #[test]
-    fn request_insert_argument_overflow() {
+    fn request_validate_required_unless_field_sibling_absent() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_field(Field::new("my_field").unwrap().required_unless("skip"))
+            .unwrap()
+            .insert_flag(Flag::new("skip").unwrap())
             .unwrap();
+        let request = Request::new(&action);
 
-        let expected = Error::new("Todo: Help.");
-        let actual = Request::new(&action)
-            .insert_argument("value")
+        assert!(request.validate().is_err());
+    }
+
+    /// Request::validate must pass if a `required_unless` Field is missing
+    /// but its sibling is present.
+    ///
+    /// The validate method on Request must return Ok(()) if a Field marked
+    /// `required_unless` was not supplied but the named sibling was.
+    #[test]
+    fn request_validate_required_unless_field_sibling_present() {
+        let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument("value")
-            .unwrap_err();
+            .insert_field(Field::new("my_field").unwrap().required_unless("skip"))
+            .unwrap()
+            .insert_flag(Flag::new("skip").unwrap())
+            .unwrap();
+        let request = Request::new(&action).insert_flag("skip").unwrap();
 
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::insert_field must insert a Field.
+    /// Request::validate must fail if a `required_unless` Flag is missing
+    /// and its sibling is absent.
     ///
-    /// The insert field method must insert a Field into the Request.
+    /// The validate method on Request must return Err if a Flag marked
+    /// `required_unless` was not supplied and the named sibling is also
+    /// absent.
     #[test]
-    fn request_insert_field() {
+    fn request_validate_required_unless_flag_sibling_absent() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap())
+            .insert_flag(Flag::new("accept_terms").unwrap().required_unless("skip_terms"))
+            .unwrap()
+            .insert_flag(Flag::new("skip_terms").unwrap())
             .unwrap();
+        let request = Request::new(&action);
 
-        let mut expected = Request::new(&action);
-        expected
-            .fields
-            .insert(String::from("my_field"), Some(String::from("value")));
+        assert!(request.validate().is_err());
+    }
 
-        let actual = Request::new(&action)
-            .insert_field("my_field", "value")
+    /// Request::validate must pass if a `required_unless` Flag is missing
+    /// but its sibling is present.
+    ///
+    /// The validate method on Request must return Ok(()) if a Flag marked
+    /// `required_unless` was not supplied but the named sibling was.
+    #[test]
+    fn request_validate_required_unless_flag_sibling_present() {
+        let action = Action::<()>::new("my_action")
+            .unwrap()
+            .insert_flag(Flag::new("accept_terms").unwrap().required_unless("skip_terms"))
+            .unwrap()
+            .insert_flag(Flag::new("skip_terms").unwrap())
             .unwrap();
+        let request = Request::new(&action).insert_flag("skip_terms").unwrap();
 
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::insert_field must error if Field is not found.
+    /// Request::validate must fail if two conflicting Flags are both
+    /// present.
     ///
-    /// The insert field method must error if the Field does not exist on the Action.
+    /// The validate method on Request must return Err if a Flag's
+    /// `conflicts_with` title is present alongside it.
     #[test]
-    fn request_insert_field_not_found() {
+    fn request_validate_conflicts_with_both_present() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(Field::new("my_field").unwrap())
+            .insert_flag(Flag::new("quiet").unwrap().conflicts_with("verbose"))
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap())
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_flag("quiet")
+            .unwrap()
+            .insert_flag("verbose")
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
-        let actual = Request::new(&action)
-            .insert_field("not_my_field", "value")
-            .unwrap_err();
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_err());
     }
 
-    /// Request::insert_field must error if Field filter fails.
+    /// Request::validate must pass if only one of two conflicting Flags is
+    /// present.
     ///
-    /// The insert field method must error if the Field's valdiation filter fails.
+    /// The validate method on Request must return Ok(()) if a Flag's
+    /// `conflicts_with` title is absent.
     #[test]
-    fn request_insert_field_fail_filter() {
+    fn request_validate_conflicts_with_one_absent() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_field(
-                Field::new("my_field")
-                    .unwrap()
-                    .filter(|_| -> bool { false }),
-            )
+            .insert_flag(Flag::new("quiet").unwrap().conflicts_with("verbose"))
+            .unwrap()
+            .insert_flag(Flag::new("verbose").unwrap())
             .unwrap();
+        let request = Request::new(&action).insert_flag("quiet").unwrap();
 
-        let expected = Error::new("Todo: Help.");
-        let actual = Request::new(&action)
-            .insert_field("my_field", "value")
-            .unwrap_err();
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::insert_flag must insert a Flag.
+    /// Request::validate must fail when two Fields linked by
+    /// `required_unless`/`conflicts_with` are both given, and when neither
+    /// is given.
     ///
-    /// The insert flag method must insert a Flag into the Request.
+    /// Mirrors a `--config`/`id` pair where each is required unless the
+    /// other is present and they also conflict, so exactly one of the two
+    /// must be supplied.
     #[test]
-    fn request_insert_flag() {
+    fn request_validate_required_unless_and_conflicts_with_combined() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_field(Field::new("config").unwrap().required_unless("id"))
+            .unwrap()
+            .insert_field(Field::new("id").unwrap().conflicts_with("config"))
             .unwrap();
 
-        let mut expected = Request::new(&action);
-        expected.flags.insert(String::from("my_flag"), true);
+        let both = Request::new(&action)
+            .insert_field("config", "a")
+            .unwrap()
+            .insert_field("id", "b")
+            .unwrap();
+        assert!(both.validate().is_err());
 
-        let actual = Request::new(&action).insert_flag("my_flag").unwrap();
+        let neither = Request::new(&action);
+        assert!(neither.validate().is_err());
 
-        assert_eq!(expected, actual);
+        let only_config = Request::new(&action)
+            .insert_field("config", "a")
+            .unwrap();
+        assert!(only_config.validate().is_ok());
+
+        let only_id = Request::new(&action).insert_field("id", "b").unwrap();
+        assert!(only_id.validate().is_ok());
     }
 
-    /// Request::insert_flag must error if Flag is not found.
+    /// Request::validate must fail if a `requires` antecedent is present
+    /// without its consequent.
     ///
-    /// The insert flag method must error if the Flag does not exist on the Action.
+    /// The validate method on Request must return Err if a Flag's `requires`
+    /// title is absent while the Flag itself is present.
     #[test]
-    fn request_insert_flag_not_found() {
+    fn request_validate_requires_consequent_missing() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_flag(Flag::new("my_flag").unwrap())
+            .insert_flag(Flag::new("force").unwrap().requires(&["confirm"]))
+            .unwrap()
+            .insert_flag(Flag::new("confirm").unwrap())
             .unwrap();
+        let request = Request::new(&action).insert_flag("force").unwrap();
 
-        let expected = Error::new("Todo: Help.");
-        let actual = Request::new(&action)
-            .insert_flag("not_my_flag")
-            .unwrap_err();
-        assert_eq!(expected, actual);
+        assert!(request.validate().is_err());
     }
 
-    /// Request::run must run the Action's then callback.
+    /// Request::validate must pass if a `requires` antecedent and its
+    /// consequent are both present.
     ///
-    /// The run method on Request must run the Action's callback it references.
+    /// The validate method on Request must return Ok(()) if a Flag's
+    /// `requires` title is present alongside it.
     #[test]
-    fn request_run() {
-        let action = Action::new("my_action")
+    fn request_validate_requires_consequent_present() {
+        let action = Action::<()>::new("my_action")
             .unwrap()
-            .then(|_request| -> u8 { 1_u8 });
-        let request = Request::new(&action);
+            .insert_flag(Flag::new("force").unwrap().requires(&["confirm"]))
+            .unwrap()
+            .insert_flag(Flag::new("confirm").unwrap())
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_flag("force")
+            .unwrap()
+            .insert_flag("confirm")
+            .unwrap();
 
-        assert_eq!(1, request.run().unwrap());
+        assert!(request.validate().is_ok());
     }
 
-    /// Request::validate must successfully validate the Action.
+    /// Request::validate_groups must pass when a Group's rules are met.
     ///
-    /// The validate method on Request must return true if the correct number of
-    /// Arguments, Fields and Flags were supplied.
+    /// The validate_groups method must return Ok when only one of a
+    /// non-multiple Group's args is present.
     #[test]
-    fn request_validate() {
+    fn request_validate_groups_pass() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_field(Field::new("json").unwrap())
+            .unwrap()
+            .insert_field(Field::new("yaml").unwrap())
+            .unwrap()
+            .insert_group(
+                Group::new("format")
+                    .unwrap()
+                    .args(&["json", "yaml"])
+                    .multiple(false),
+            )
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_field("json", "value")
             .unwrap();
-        let request = Request::new(&action).insert_argument("first").unwrap();
 
-        assert!(request.validate());
+        assert!(request.validate_groups().is_ok());
     }
 
-    /// Request::validate must successfully validate the Action.
+    /// Request::validate_groups must error on mutual exclusion violations.
     ///
-    /// The validate method on Request must return false if too many Arguments were
-    /// supplied.
+    /// The validate_groups method must return a GroupViolation Error when
+    /// more than one of a non-multiple Group's args is present.
     #[test]
-    fn request_validate_argument_overflow() {
+    fn request_validate_groups_conflict() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_field(Field::new("json").unwrap())
+            .unwrap()
+            .insert_field(Field::new("yaml").unwrap())
+            .unwrap()
+            .insert_group(
+                Group::new("format")
+                    .unwrap()
+                    .args(&["json", "yaml"])
+                    .multiple(false),
+            )
+            .unwrap();
+        let request = Request::new(&action)
+            .insert_field("json", "value")
+            .unwrap()
+            .insert_field("yaml", "value")
             .unwrap();
-        let mut request = Request::new(&action);
-        request.arguments.push(String::from("first"));
-        request.arguments.push(String::from("second"));
 
-        assert!(!request.validate());
+        let expected = Error::group_violation(
+            "format",
+            "argument '--json' cannot be used with '--yaml'",
+        );
+        assert_eq!(expected, request.validate_groups().unwrap_err());
     }
 
-    /// Request::validate must successfully validate the Action.
+    /// Request::validate_groups must error when a required Group is empty.
     ///
-    /// The validate method on Request must return false if too few Arguments were
-    /// supplied.
+    /// The validate_groups method must return a GroupViolation Error when
+    /// none of a required Group's args are present.
     #[test]
-    fn request_validate_argument_underflow() {
+    fn request_validate_groups_required_missing() {
         let action = Action::<()>::new("my_action")
             .unwrap()
-            .insert_argument(Argument::new("my_argument").unwrap())
+            .insert_field(Field::new("json").unwrap())
+            .unwrap()
+            .insert_field(Field::new("yaml").unwrap())
+            .unwrap()
+            .insert_group(
+                Group::new("format")
+                    .unwrap()
+                    .args(&["json", "yaml"])
+                    .required(true),
+            )
             .unwrap();
         let request = Request::new(&action);
 
-        assert!(!request.validate());
+        let expected = Error::group_violation(
+            "format",
+            "one of '--json', '--yaml' is required",
+        );
+        assert_eq!(expected, request.validate_groups().unwrap_err());
     }
 }
@@ -0,0 +1,177 @@
+//! Completion.
+//!
+//! Assembles shell completion scripts from a slice of top-level Actions.
+//! Each function here renders one shell's script format, delegating the
+//! recursive tree-walking to the matching `pub(crate)` method on `Action`.
+
+use crate::action::Action;
+
+/// Build the completion function name for a binary.
+///
+/// Shell function names can't contain `-` or `.`, so both are normalised to
+/// `_` before the name is used across all four generators.
+fn function_name(bin_name: &str) -> String {
+    bin_name.replace('-', "_").replace('.', "_")
+}
+
+/// Generate a bash completion script.
+///
+/// Builds a `complete -F` function that cases on `COMP_CWORD` for each
+/// depth reachable through the Action tree, nesting a `${COMP_WORDS[n]}`
+/// case per level to select the right branch.
+pub(crate) fn bash<T>(actions: &[&Action<T>], bin_name: &str) -> String {
+    let function = format!("_{}_completions", function_name(bin_name));
+    let max_depth = actions
+        .iter()
+        .map(|action| action.max_completion_depth(2))
+        .max()
+        .unwrap_or(1);
+
+    let mut cases = String::new();
+    for target in 1..=max_depth {
+        cases.push_str(&format!("        {target})\n"));
+        if target == 1 {
+            let keywords: Vec<&str> = actions.iter().map(|action| action.keyword.as_str()).collect();
+            cases.push_str(&format!("            opts=\"{}\"\n", keywords.join(" ")));
+        } else {
+            cases.push_str("            case \"${COMP_WORDS[1]}\" in\n");
+            for action in actions {
+                if action.max_completion_depth(2) >= target {
+                    cases.push_str(&format!("                {})\n", action.keyword));
+                    cases.push_str(&action.bash_case_chain(2, target, 5));
+                    cases.push_str("                    ;;\n");
+                }
+            }
+            cases.push_str("            esac\n");
+        }
+        cases.push_str("            ;;\n");
+    }
+
+    format!(
+        "{function}() {{\n    \
+         local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    \
+         local opts=\"\"\n\n    \
+         case \"${{COMP_CWORD}}\" in\n\
+         {cases}    \
+         esac\n\n    \
+         COMPREPLY=( $(compgen -W \"$opts\" -- \"$cur\") )\n\
+         }}\n\
+         complete -F {function} {bin_name}\n"
+    )
+}
+
+/// Generate a zsh completion script.
+///
+/// Emits a `_{bin_name}` function built from `_arguments` specs (carrying
+/// each Argument/Field/Flag's `description` as inline help text), with one
+/// nested function per Action reachable in the tree, and registers it via
+/// `compdef`.
+pub(crate) fn zsh<T>(actions: &[&Action<T>], bin_name: &str) -> String {
+    let function = format!("_{}", function_name(bin_name));
+
+    let subcommands: Vec<String> = actions
+        .iter()
+        .map(|action| format!("'{}:{}'", action.keyword, action.description_str().unwrap_or("")))
+        .collect();
+
+    let mut text = format!(
+        "{function}() {{\n    \
+         local -a subcommands\n    \
+         subcommands=({})\n\n    \
+         _arguments -C \\\n        \
+         '1: :->command' \\\n        \
+         '*::arg:->args'\n\n    \
+         case $state in\n        \
+         command)\n            \
+         _describe 'command' subcommands\n            \
+         ;;\n        \
+         args)\n            \
+         case $words[1] in\n",
+        subcommands.join(" ")
+    );
+
+    for action in actions {
+        text.push_str(&format!(
+            "                {})\n                    {function}_{}\n                    ;;\n",
+            action.keyword, action.keyword
+        ));
+    }
+    text.push_str("            esac\n            ;;\n    esac\n}\n");
+
+    for action in actions {
+        text.push('\n');
+        text.push_str(&action.zsh_function(&format!("{function}_{}", action.keyword)));
+    }
+
+    text.push_str(&format!("\ncompdef {function} {bin_name}\n"));
+    text
+}
+
+/// Generate a fish completion script.
+///
+/// Emits one `complete -c` line per child Action, Field and Flag reachable
+/// in the tree, each conditioned on the `__fish_seen_subcommand_from` chain
+/// of keywords that must already have been typed for it to apply.
+pub(crate) fn fish<T>(actions: &[&Action<T>], bin_name: &str) -> String {
+    let mut text = String::new();
+    for action in actions {
+        text.push_str(&format!(
+            "complete -c {bin_name} -n '__fish_use_subcommand' -a '{}' -d '{}'\n",
+            action.keyword,
+            action.description_str().unwrap_or("")
+        ));
+    }
+    for action in actions {
+        action.fish_complete_lines(bin_name, &[], &mut text);
+    }
+    text
+}
+
+/// Generate a PowerShell completion script.
+///
+/// Registers a `Register-ArgumentCompleter` block that tokenises the
+/// command line typed so far and nests a `switch` per depth reachable
+/// through the Action tree, mirroring the bash generator's case chain.
+pub(crate) fn powershell<T>(actions: &[&Action<T>], bin_name: &str) -> String {
+    let max_depth = actions
+        .iter()
+        .map(|action| action.max_completion_depth(2))
+        .max()
+        .unwrap_or(0);
+
+    let mut cases = String::new();
+    for target in 1..=max_depth.max(1) {
+        cases.push_str(&format!("        {target} {{\n"));
+        if target == 1 {
+            let keywords: Vec<String> = actions.iter().map(|action| format!("'{}'", action.keyword)).collect();
+            cases.push_str(&format!("            @({})\n", keywords.join(", ")));
+        } else {
+            cases.push_str("            switch ($tokens[1]) {\n");
+            for action in actions {
+                if action.max_completion_depth(2) >= target {
+                    cases.push_str(&format!("                '{}' {{\n", action.keyword));
+                    cases.push_str(&action.powershell_case_chain(2, target, 5));
+                    cases.push_str("                }\n");
+                }
+            }
+            cases.push_str("                default { @() }\n            }\n");
+        }
+        cases.push_str("        }\n");
+    }
+
+    format!(
+        "Register-ArgumentCompleter -Native -CommandName {bin_name} -ScriptBlock {{\n    \
+         param($wordToComplete, $commandAst, $cursorPosition)\n    \
+         $tokens = $commandAst.CommandElements | ForEach-Object {{ $_.ToString() }}\n    \
+         $depth = $tokens.Count\n    \
+         if ($wordToComplete -ne '') {{ $depth = $depth - 1 }}\n\n    \
+         $candidates = switch ($depth) {{\n\
+         {cases}        \
+         default {{ @() }}\n    \
+         }}\n\n    \
+         $candidates | Where-Object {{ $_ -like \"$wordToComplete*\" }} | ForEach-Object {{\n        \
+         [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)\n    \
+         }}\n\
+         }}\n"
+    )
+}
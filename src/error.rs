@@ -7,13 +7,105 @@
 use core::fmt::{self, Display, Formatter};
 use core::result;
 use std::error::Error as StdError;
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::rc::Rc;
+
+/// Position.
+///
+/// A single point within a string command, carried by `Error::SyntaxError` so
+/// that callers can locate exactly where scanning broke down.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    /// The byte offset into the scanned command.
+    pub offset: usize,
+    /// The zero-indexed line number the offset falls on.
+    pub line: usize,
+    /// The zero-indexed column, in characters, within that line.
+    pub column: usize,
+}
+
+impl Pos {
+    /// Create a new Pos.
+    ///
+    /// Construct a new Pos from a byte offset, line number and column.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Pos;
+    ///
+    /// Pos::new(0, 0, 0);
+    /// ```
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+impl Display for Pos {
+    /// Format a Pos for display.
+    ///
+    /// Formats the position as `line:column`, matching common compiler
+    /// diagnostic conventions.
+    ///
+    /// # Error
+    /// Will error if the underlying write macro fails.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.column + 1)
+    }
+}
+
+/// Compute the Levenshtein edit distance between two strings.
+///
+/// Counts the minimum number of single-character insertions, deletions, or
+/// substitutions required to turn `a` into `b`, using the standard dynamic
+/// programming table reduced to a single rolling row.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ac) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &bc) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ac == bc {
+                diagonal
+            } else {
+                1 + diagonal.min(row[j]).min(above)
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Suggest the closest candidate to an unrecognised input.
+///
+/// Scans `candidates` for the entry with the smallest Levenshtein distance to
+/// `input`, returning it unless even the closest match is too dissimilar to
+/// be a useful suggestion (further away than `max(2, input.len() / 3)`).
+fn suggest<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let threshold = (input.chars().count() / 3).max(2);
+
+    candidates
+        .map(|candidate| (candidate, levenshtein(input, candidate)))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| String::from(candidate))
+}
 
 /// Error.
 ///
 /// Typed error for the library. Utilised for all errors raised from this
-/// library. Uses a provided String as the internal error message. Can be used
-/// in a core::result::Result, however, for convenience a Result type is
-/// provided in this module.
+/// library. Non-exhaustive so further variants can be added without being a
+/// breaking change. Can be used in a core::result::Result, however, for
+/// convenience a Result type is provided in this module.
 ///
 /// Example
 /// ```rust
@@ -24,12 +116,241 @@ use std::error::Error as StdError;
 ///     Ok(())
 /// }
 /// ```
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Error {
-    /// The error message.
-    message: String,
+///
+/// `From<std::io::Error>`, `From<std::num::ParseIntError>` and
+/// `From<std::num::ParseFloatError>` are provided so standard library errors
+/// can be lifted into an Error with the `?` operator; the original error is
+/// kept so `StdError::source` can return it. Because those wrapped errors
+/// don't implement `Hash`, `Ord` or `PartialOrd`, this enum only derives
+/// `Clone` and `Debug`; `PartialEq`/`Eq` are implemented manually below,
+/// comparing the Io/ParseInt/ParseFloat variants by message only (the same
+/// approach this crate takes for Argument and Field, whose closure fields
+/// are likewise excluded from equality).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An action keyword was not recognised.
+    UnknownAction {
+        /// The unrecognised input keyword.
+        input: String,
+        /// The closest registered Action keyword, if one is close enough to
+        /// be worth suggesting.
+        suggestion: Option<String>,
+    },
+    /// A `--flag` (or `-f`) was not recognised for the selected Action.
+    UnknownFlag {
+        /// The keyword of the Action the flag was looked up against.
+        action: String,
+        /// The unrecognised flag token.
+        token: String,
+        /// The closest registered Flag title, if one is close enough to be
+        /// worth suggesting.
+        suggestion: Option<String>,
+    },
+    /// A Field was given no value.
+    MissingFieldValue {
+        /// The Field's title.
+        field: String,
+    },
+    /// A Field value could not be parsed as its declared FieldType.
+    InvalidFieldValue {
+        /// The Field's title.
+        field: String,
+        /// The offending value token.
+        value: String,
+        /// A human readable description of the expected type.
+        expected: String,
+    },
+    /// A `value_parser` callback rejected a value.
+    InvalidValue {
+        /// The title of the Argument or Field the value was given for.
+        target: String,
+        /// The offending value token.
+        value: String,
+        /// The message returned by the `value_parser` callback.
+        message: String,
+    },
+    /// A value outside the Argument or Field's `possible_values` was given.
+    InvalidChoice {
+        /// The title of the Argument or Field the value was given for.
+        target: String,
+        /// The offending value token.
+        value: String,
+        /// The valid choices accepted by the Argument or Field.
+        choices: Vec<String>,
+    },
+    /// A required Argument was not provided.
+    MissingArgument {
+        /// The keyword of the Action the argument belongs to.
+        action: String,
+        /// The Argument's title.
+        name: String,
+    },
+    /// A declared Group's constraint was violated.
+    GroupViolation {
+        /// The Group's title.
+        group: String,
+        /// A human readable description of the violated constraint.
+        message: String,
+    },
+    /// A Request failed `Request::validate`.
+    ValidationFailed {
+        /// One descriptive message per unmet Argument, Field or Flag
+        /// requirement, listing all of them at once.
+        messages: Vec<String>,
+    },
+    /// A syntax error occurred while scanning a string command.
+    SyntaxError {
+        /// A human readable description of the syntax error.
+        message: String,
+        /// The position the error starts at.
+        start: Pos,
+        /// The position the error ends at, if it spans more than one point.
+        end: Option<Pos>,
+    },
+    /// An I/O operation failed.
+    Io {
+        /// A human readable description of the I/O failure.
+        message: String,
+        /// The original io::Error, preserved so `source` can return it.
+        source: Rc<io::Error>,
+    },
+    /// A value could not be parsed as an integer.
+    ParseInt {
+        /// A human readable description of the parse failure.
+        message: String,
+        /// The original ParseIntError, preserved so `source` can return it.
+        source: Rc<ParseIntError>,
+    },
+    /// A value could not be parsed as a float.
+    ParseFloat {
+        /// A human readable description of the parse failure.
+        message: String,
+        /// The original ParseFloatError, preserved so `source` can return it.
+        source: Rc<ParseFloatError>,
+    },
+    /// Any other error message, not covered by a more specific variant.
+    Other(String),
 }
 
+impl PartialEq for Error {
+    /// Partial Equality implementation.
+    ///
+    /// Defines how Errors should be considered equal. The Io, ParseInt and
+    /// ParseFloat variants compare by message only, since the wrapped source
+    /// error doesn't implement PartialEq.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::UnknownAction {
+                    input: i1,
+                    suggestion: s1,
+                },
+                Self::UnknownAction {
+                    input: i2,
+                    suggestion: s2,
+                },
+            ) => i1 == i2 && s1 == s2,
+            (
+                Self::UnknownFlag {
+                    action: a1,
+                    token: t1,
+                    suggestion: s1,
+                },
+                Self::UnknownFlag {
+                    action: a2,
+                    token: t2,
+                    suggestion: s2,
+                },
+            ) => a1 == a2 && t1 == t2 && s1 == s2,
+            (Self::MissingFieldValue { field: f1 }, Self::MissingFieldValue { field: f2 }) => {
+                f1 == f2
+            }
+            (
+                Self::InvalidFieldValue {
+                    field: f1,
+                    value: v1,
+                    expected: e1,
+                },
+                Self::InvalidFieldValue {
+                    field: f2,
+                    value: v2,
+                    expected: e2,
+                },
+            ) => f1 == f2 && v1 == v2 && e1 == e2,
+            (
+                Self::InvalidValue {
+                    target: t1,
+                    value: v1,
+                    message: m1,
+                },
+                Self::InvalidValue {
+                    target: t2,
+                    value: v2,
+                    message: m2,
+                },
+            ) => t1 == t2 && v1 == v2 && m1 == m2,
+            (
+                Self::InvalidChoice {
+                    target: t1,
+                    value: v1,
+                    choices: c1,
+                },
+                Self::InvalidChoice {
+                    target: t2,
+                    value: v2,
+                    choices: c2,
+                },
+            ) => t1 == t2 && v1 == v2 && c1 == c2,
+            (
+                Self::MissingArgument {
+                    action: a1,
+                    name: n1,
+                },
+                Self::MissingArgument {
+                    action: a2,
+                    name: n2,
+                },
+            ) => a1 == a2 && n1 == n2,
+            (
+                Self::GroupViolation {
+                    group: g1,
+                    message: m1,
+                },
+                Self::GroupViolation {
+                    group: g2,
+                    message: m2,
+                },
+            ) => g1 == g2 && m1 == m2,
+            (
+                Self::ValidationFailed { messages: m1 },
+                Self::ValidationFailed { messages: m2 },
+            ) => m1 == m2,
+            (
+                Self::SyntaxError {
+                    message: m1,
+                    start: s1,
+                    end: e1,
+                },
+                Self::SyntaxError {
+                    message: m2,
+                    start: s2,
+                    end: e2,
+                },
+            ) => m1 == m2 && s1 == s2 && e1 == e2,
+            (Self::Io { message: m1, .. }, Self::Io { message: m2, .. }) => m1 == m2,
+            (Self::ParseInt { message: m1, .. }, Self::ParseInt { message: m2, .. }) => m1 == m2,
+            (Self::ParseFloat { message: m1, .. }, Self::ParseFloat { message: m2, .. }) => {
+                m1 == m2
+            }
+            (Self::Other(m1), Self::Other(m2)) => m1 == m2,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
 impl Error {
     /// Create a new Error.
     ///
@@ -42,16 +363,208 @@ impl Error {
     /// Error::new("Something went wrong...");
     /// ```
     pub fn new(message: &str) -> Self {
-        Self {
+        Self::Other(String::from(message))
+    }
+
+    /// Create a new unknown action Error.
+    ///
+    /// Construct a new Error reporting that `input` did not match any
+    /// registered Action keyword, suggesting the closest `candidates` entry
+    /// (by Levenshtein distance) if one is close enough to be useful.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::unknown_action("my_acton", ["my_action"].into_iter());
+    /// ```
+    pub fn unknown_action<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Self {
+        Self::UnknownAction {
+            input: String::from(input),
+            suggestion: suggest(input, candidates),
+        }
+    }
+
+    /// Create a new unknown flag Error.
+    ///
+    /// Construct a new Error reporting that `token` did not match any Field
+    /// or Flag registered on the `action` keyword, suggesting the closest
+    /// `candidates` entry (by Levenshtein distance) if one is close enough
+    /// to be useful.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::unknown_flag("my_action", "verbse", ["verbose"].into_iter());
+    /// ```
+    pub fn unknown_flag<'a>(
+        action: &str,
+        token: &str,
+        candidates: impl Iterator<Item = &'a str>,
+    ) -> Self {
+        Self::UnknownFlag {
+            action: String::from(action),
+            token: String::from(token),
+            suggestion: suggest(token, candidates),
+        }
+    }
+
+    /// Create a new missing field value Error.
+    ///
+    /// Construct a new Error reporting that the `field` Field was given no
+    /// value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::missing_field_value("output");
+    /// ```
+    pub fn missing_field_value(field: &str) -> Self {
+        Self::MissingFieldValue {
+            field: String::from(field),
+        }
+    }
+
+    /// Create a new invalid field value Error.
+    ///
+    /// Construct a new Error reporting that `value`, given for the `field`
+    /// Field, could not be parsed as `expected` (a human readable
+    /// description of the Field's declared FieldType).
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::invalid_field_value("port", "abc", "an integer");
+    /// ```
+    pub fn invalid_field_value(field: &str, value: &str, expected: &str) -> Self {
+        Self::InvalidFieldValue {
+            field: String::from(field),
+            value: String::from(value),
+            expected: String::from(expected),
+        }
+    }
+
+    /// Create a new invalid value Error.
+    ///
+    /// Construct a new Error reporting that `value`, given for the `target`
+    /// Argument or Field, was rejected by its `value_parser` callback with
+    /// `message`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::invalid_value("port", "70000", "not in range 0-65535");
+    /// ```
+    pub fn invalid_value(target: &str, value: &str, message: &str) -> Self {
+        Self::InvalidValue {
+            target: String::from(target),
+            value: String::from(value),
+            message: String::from(message),
+        }
+    }
+
+    /// Create a new invalid choice Error.
+    ///
+    /// Construct a new Error reporting that `value`, given for the `target`
+    /// Argument or Field, is not one of its `choices`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::invalid_choice("mode", "update", &[String::from("add"), String::from("remove")]);
+    /// ```
+    pub fn invalid_choice(target: &str, value: &str, choices: &[String]) -> Self {
+        Self::InvalidChoice {
+            target: String::from(target),
+            value: String::from(value),
+            choices: choices.to_vec(),
+        }
+    }
+
+    /// Create a new missing argument Error.
+    ///
+    /// Construct a new Error reporting that the `name` Argument, belonging to
+    /// the `action` keyword, was not provided.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::missing_argument("my_action", "one");
+    /// ```
+    pub fn missing_argument(action: &str, name: &str) -> Self {
+        Self::MissingArgument {
+            action: String::from(action),
+            name: String::from(name),
+        }
+    }
+
+    /// Create a new group violation Error.
+    ///
+    /// Construct a new Error reporting that the `group` Group's constraint
+    /// was violated, described by `message`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::group_violation("format", "argument '--json' cannot be used with '--yaml'");
+    /// ```
+    pub fn group_violation(group: &str, message: &str) -> Self {
+        Self::GroupViolation {
+            group: String::from(group),
             message: String::from(message),
         }
     }
+
+    /// Create a new validation failed Error.
+    ///
+    /// Construct a new Error reporting that a Request failed
+    /// `Request::validate`, carrying every unmet requirement's message.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// Error::validation_failed(&[String::from("Field 'output' is required.")]);
+    /// ```
+    pub fn validation_failed(messages: &[String]) -> Self {
+        Self::ValidationFailed {
+            messages: messages.to_vec(),
+        }
+    }
+
+    /// Create a new syntax Error.
+    ///
+    /// Construct a new Error reporting a `message` describing a syntax
+    /// error, starting at `start` and, if it spans more than one point,
+    /// ending at `end`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Error, Pos};
+    ///
+    /// Error::syntax("unterminated quote", Pos::new(4, 0, 4), None);
+    /// ```
+    pub fn syntax(message: &str, start: Pos, end: Option<Pos>) -> Self {
+        Self::SyntaxError {
+            message: String::from(message),
+            start,
+            end,
+        }
+    }
 }
 
 impl Display for Error {
     /// Format an Error for display.
     ///
-    /// Formats the error for display and pretty printing.
+    /// Formats the error for display and pretty printing. A SyntaxError is
+    /// rendered with a caret underlining the position it starts at.
     ///
     /// # Example
     /// ```
@@ -64,11 +577,170 @@ impl Display for Error {
     /// # Error
     /// Will error if the underlying write macro fails.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Error: {}", self.message)
+        match self {
+            Self::UnknownAction { input, suggestion } => {
+                write!(f, "Error: Unknown action '{input}'.")?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean '{suggestion}'?")?;
+                }
+                Ok(())
+            }
+            Self::UnknownFlag {
+                action,
+                token,
+                suggestion,
+            } => {
+                write!(f, "Error: Unknown flag '{token}' for action '{action}'.")?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean '{suggestion}'?")?;
+                }
+                Ok(())
+            }
+            Self::MissingFieldValue { field } => {
+                write!(f, "Error: Field '{field}' was given no value.")
+            }
+            Self::InvalidFieldValue {
+                field,
+                value,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "Error: Value '{value}' for Field '{field}' is not {expected}."
+                )
+            }
+            Self::InvalidValue {
+                target,
+                value,
+                message,
+            } => {
+                write!(f, "Error: Value '{value}' for '{target}' is invalid: {message}.")
+            }
+            Self::InvalidChoice {
+                target,
+                value,
+                choices,
+            } => {
+                write!(
+                    f,
+                    "Error: Value '{value}' for '{target}' is not a valid choice: [{}].",
+                    choices.join(", ")
+                )
+            }
+            Self::MissingArgument { action, name } => {
+                write!(
+                    f,
+                    "Error: Argument '{name}' is required by action '{action}'."
+                )
+            }
+            Self::GroupViolation { group, message } => {
+                write!(f, "Error: Group '{group}' violated: {message}.")
+            }
+            Self::ValidationFailed { messages } => {
+                write!(f, "Error: {}", messages.join(" "))
+            }
+            Self::SyntaxError { message, start, .. } => {
+                write!(f, "Error: {message} at {start}.\n{}^", " ".repeat(start.column))
+            }
+            Self::Io { message, .. } => write!(f, "Error: I/O error: {message}."),
+            Self::ParseInt { message, .. } => write!(f, "Error: {message}."),
+            Self::ParseFloat { message, .. } => write!(f, "Error: {message}."),
+            Self::Other(message) => write!(f, "Error: {message}"),
+        }
+    }
+}
+
+impl StdError for Error {
+    /// Return the underlying cause of an Error, if one was preserved.
+    ///
+    /// Only the Io, ParseInt and ParseFloat variants carry a source, since
+    /// they're constructed from an underlying standard library error via
+    /// `From`; every other variant originates within this crate and has no
+    /// further cause to report.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source.as_ref()),
+            Self::ParseInt { source, .. } => Some(source.as_ref()),
+            Self::ParseFloat { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    /// Lift a std::io::Error into an Error.
+    ///
+    /// Wraps `source` in the Io variant, preserving it so `StdError::source`
+    /// can return it, and allowing `?` to convert an io::Error directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    /// use std::fs;
+    ///
+    /// fn read() -> cherry::Result<String> {
+    ///     Ok(fs::read_to_string("/does/not/exist")?)
+    /// }
+    ///
+    /// assert!(read().is_err());
+    /// ```
+    fn from(source: io::Error) -> Self {
+        Self::Io {
+            message: source.to_string(),
+            source: Rc::new(source),
+        }
+    }
+}
+
+impl From<ParseIntError> for Error {
+    /// Lift a std::num::ParseIntError into an Error.
+    ///
+    /// Wraps `source` in the ParseInt variant, preserving it so
+    /// `StdError::source` can return it, and allowing `?` to convert a
+    /// ParseIntError directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// fn parse(value: &str) -> cherry::Result<i64> {
+    ///     Ok(value.parse::<i64>()?)
+    /// }
+    ///
+    /// assert!(parse("abc").is_err());
+    /// ```
+    fn from(source: ParseIntError) -> Self {
+        Self::ParseInt {
+            message: source.to_string(),
+            source: Rc::new(source),
+        }
     }
 }
 
-impl StdError for Error {}
+impl From<ParseFloatError> for Error {
+    /// Lift a std::num::ParseFloatError into an Error.
+    ///
+    /// Wraps `source` in the ParseFloat variant, preserving it so
+    /// `StdError::source` can return it, and allowing `?` to convert a
+    /// ParseFloatError directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Error;
+    ///
+    /// fn parse(value: &str) -> cherry::Result<f64> {
+    ///     Ok(value.parse::<f64>()?)
+    /// }
+    ///
+    /// assert!(parse("abc").is_err());
+    /// ```
+    fn from(source: ParseFloatError) -> Self {
+        Self::ParseFloat {
+            message: source.to_string(),
+            source: Rc::new(source),
+        }
+    }
+}
 
 /// Result.
 ///
@@ -90,16 +762,15 @@ pub type Result<T> = result::Result<T, Error>;
 mod tests {
 
     use super::*;
+    use std::iter;
 
-    /// Error::new must create as per struct initialisation.
+    /// Error::new must create an Other Error.
     ///
-    /// The new method on Error must create an object as per the struct
-    /// initialiser syntax.
+    /// The new method on Error must create an Other variant wrapping the
+    /// provided message.
     #[test]
     fn error_new() {
-        let expected = Error {
-            message: String::from("Message"),
-        };
+        let expected = Error::Other(String::from("Message"));
         let actual = Error::new("Message");
         assert_eq!(expected, actual);
     }
@@ -115,4 +786,449 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    /// Error::unknown_action must create an UnknownAction Error.
+    ///
+    /// The unknown_action method on Error must create an object as per the
+    /// UnknownAction variant initialiser syntax, with no suggestion when no
+    /// candidate is close enough.
+    #[test]
+    fn error_unknown_action() {
+        let expected = Error::UnknownAction {
+            input: String::from("my_action"),
+            suggestion: None,
+        };
+        let actual = Error::unknown_action("my_action", iter::empty());
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::unknown_action must suggest the closest candidate.
+    ///
+    /// The unknown_action method on Error must populate suggestion with the
+    /// candidate closest to input by Levenshtein distance, when one is close
+    /// enough to be useful.
+    #[test]
+    fn error_unknown_action_suggestion() {
+        let expected = Error::UnknownAction {
+            input: String::from("my_acton"),
+            suggestion: Some(String::from("my_action")),
+        };
+        let actual = Error::unknown_action("my_acton", ["my_action", "other"].into_iter());
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display an UnknownAction Error.
+    ///
+    /// The Display trait must render a readable message for an UnknownAction
+    /// Error, including the suggestion when one is present.
+    #[test]
+    fn error_unknown_action_fmt() {
+        let expected = "Error: Unknown action 'my_acton'. Did you mean 'my_action'?";
+        let actual = Error::unknown_action("my_acton", ["my_action"].into_iter()).to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::unknown_flag must create an UnknownFlag Error.
+    ///
+    /// The unknown_flag method on Error must create an object as per the
+    /// UnknownFlag variant initialiser syntax, with no suggestion when no
+    /// candidate is close enough.
+    #[test]
+    fn error_unknown_flag() {
+        let expected = Error::UnknownFlag {
+            action: String::from("my_action"),
+            token: String::from("verbose"),
+            suggestion: None,
+        };
+        let actual = Error::unknown_flag("my_action", "verbose", iter::empty());
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::unknown_flag must suggest the closest candidate.
+    ///
+    /// The unknown_flag method on Error must populate suggestion with the
+    /// candidate closest to token by Levenshtein distance, when one is close
+    /// enough to be useful.
+    #[test]
+    fn error_unknown_flag_suggestion() {
+        let expected = Error::UnknownFlag {
+            action: String::from("my_action"),
+            token: String::from("verbse"),
+            suggestion: Some(String::from("verbose")),
+        };
+        let actual = Error::unknown_flag("my_action", "verbse", ["verbose"].into_iter());
+        assert_eq!(expected, actual);
+    }
+
+    /// suggest must return None when nothing is close enough.
+    ///
+    /// The private suggest function must not propose a candidate whose
+    /// Levenshtein distance from input exceeds the max(2, len / 3) threshold.
+    #[test]
+    fn suggest_too_far() {
+        let expected = None;
+        let actual = suggest("abc", ["xyz"].into_iter());
+        assert_eq!(expected, actual);
+    }
+
+    /// levenshtein must compute the edit distance between two strings.
+    ///
+    /// The private levenshtein function must return the minimum number of
+    /// single-character edits required to turn one string into the other.
+    #[test]
+    fn levenshtein_distance() {
+        let expected = 3;
+        let actual = levenshtein("kitten", "sitting");
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::missing_field_value must create a MissingFieldValue Error.
+    ///
+    /// The missing_field_value method on Error must create an object as per
+    /// the MissingFieldValue variant initialiser syntax.
+    #[test]
+    fn error_missing_field_value() {
+        let expected = Error::MissingFieldValue {
+            field: String::from("output"),
+        };
+        let actual = Error::missing_field_value("output");
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::invalid_field_value must create an InvalidFieldValue Error.
+    ///
+    /// The invalid_field_value method on Error must create an object as per
+    /// the InvalidFieldValue variant initialiser syntax.
+    #[test]
+    fn error_invalid_field_value() {
+        let expected = Error::InvalidFieldValue {
+            field: String::from("port"),
+            value: String::from("abc"),
+            expected: String::from("an integer"),
+        };
+        let actual = Error::invalid_field_value("port", "abc", "an integer");
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display an InvalidFieldValue Error.
+    ///
+    /// The Display trait must render a readable message for an
+    /// InvalidFieldValue Error.
+    #[test]
+    fn error_invalid_field_value_fmt() {
+        let expected = "Error: Value 'abc' for Field 'port' is not an integer.";
+        let actual = Error::invalid_field_value("port", "abc", "an integer").to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::invalid_value must create an InvalidValue Error.
+    ///
+    /// The invalid_value method on Error must create an object as per the
+    /// InvalidValue variant initialiser syntax.
+    #[test]
+    fn error_invalid_value() {
+        let expected = Error::InvalidValue {
+            target: String::from("port"),
+            value: String::from("70000"),
+            message: String::from("not in range 0-65535"),
+        };
+        let actual = Error::invalid_value("port", "70000", "not in range 0-65535");
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display an InvalidValue Error.
+    ///
+    /// The Display trait must render a readable message for an InvalidValue
+    /// Error.
+    #[test]
+    fn error_invalid_value_fmt() {
+        let expected = "Error: Value '70000' for 'port' is invalid: not in range 0-65535.";
+        let actual = Error::invalid_value("port", "70000", "not in range 0-65535").to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::invalid_choice must create an InvalidChoice Error.
+    ///
+    /// The invalid_choice method on Error must create an object as per the
+    /// InvalidChoice variant initialiser syntax.
+    #[test]
+    fn error_invalid_choice() {
+        let expected = Error::InvalidChoice {
+            target: String::from("mode"),
+            value: String::from("update"),
+            choices: vec![String::from("add"), String::from("remove")],
+        };
+        let actual = Error::invalid_choice(
+            "mode",
+            "update",
+            &[String::from("add"), String::from("remove")],
+        );
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display an InvalidChoice Error.
+    ///
+    /// The Display trait must render a readable message for an InvalidChoice
+    /// Error.
+    #[test]
+    fn error_invalid_choice_fmt() {
+        let expected = "Error: Value 'update' for 'mode' is not a valid choice: [add, remove].";
+        let actual = Error::invalid_choice(
+            "mode",
+            "update",
+            &[String::from("add"), String::from("remove")],
+        )
+        .to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::missing_argument must create a MissingArgument Error.
+    ///
+    /// The missing_argument method on Error must create an object as per the
+    /// MissingArgument variant initialiser syntax.
+    #[test]
+    fn error_missing_argument() {
+        let expected = Error::MissingArgument {
+            action: String::from("my_action"),
+            name: String::from("one"),
+        };
+        let actual = Error::missing_argument("my_action", "one");
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::group_violation must create a GroupViolation Error.
+    ///
+    /// The group_violation method on Error must create an object as per the
+    /// GroupViolation variant initialiser syntax.
+    #[test]
+    fn error_group_violation() {
+        let expected = Error::GroupViolation {
+            group: String::from("format"),
+            message: String::from("argument '--json' cannot be used with '--yaml'"),
+        };
+        let actual = Error::group_violation(
+            "format",
+            "argument '--json' cannot be used with '--yaml'",
+        );
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display a GroupViolation Error.
+    ///
+    /// The Display trait must render a readable message for a GroupViolation
+    /// Error.
+    #[test]
+    fn error_group_violation_fmt() {
+        let expected =
+            "Error: Group 'format' violated: argument '--json' cannot be used with '--yaml'.";
+        let actual = Error::group_violation(
+            "format",
+            "argument '--json' cannot be used with '--yaml'",
+        )
+        .to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::validation_failed must create a ValidationFailed Error.
+    ///
+    /// The validation_failed method on Error must create an object as per
+    /// the ValidationFailed variant initialiser syntax.
+    #[test]
+    fn error_validation_failed() {
+        let expected = Error::ValidationFailed {
+            messages: vec![String::from("Field 'output' is required.")],
+        };
+        let actual =
+            Error::validation_failed(&[String::from("Field 'output' is required.")]);
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display a ValidationFailed Error.
+    ///
+    /// The Display trait must render every message, space separated.
+    #[test]
+    fn error_validation_failed_fmt() {
+        let expected = "Error: Field 'output' is required. Field 'x' conflicts with 'y'.";
+        let actual = Error::validation_failed(&[
+            String::from("Field 'output' is required."),
+            String::from("Field 'x' conflicts with 'y'."),
+        ])
+        .to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::syntax must create a SyntaxError Error.
+    ///
+    /// The syntax method on Error must create an object as per the
+    /// SyntaxError variant initialiser syntax.
+    #[test]
+    fn error_syntax() {
+        let expected = Error::SyntaxError {
+            message: String::from("unterminated quote"),
+            start: Pos::new(4, 0, 4),
+            end: None,
+        };
+        let actual = Error::syntax("unterminated quote", Pos::new(4, 0, 4), None);
+        assert_eq!(expected, actual);
+    }
+
+    /// Error::fmt must display a SyntaxError Error with a caret.
+    ///
+    /// The Display trait must render the message, position, and a caret
+    /// underlining the column the error starts at.
+    #[test]
+    fn error_syntax_fmt() {
+        let expected = "Error: unterminated quote at 1:5.\n    ^";
+        let actual = Error::syntax("unterminated quote", Pos::new(4, 0, 4), None).to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Pos::new must create as per struct initialisation.
+    ///
+    /// The new method on Pos must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn pos_new() {
+        let expected = Pos {
+            offset: 4,
+            line: 0,
+            column: 4,
+        };
+        let actual = Pos::new(4, 0, 4);
+        assert_eq!(expected, actual);
+    }
+
+    /// Pos::fmt must display the Pos.
+    ///
+    /// The Display trait must render the Pos as a one-indexed `line:column`.
+    #[test]
+    fn pos_fmt() {
+        let expected = "1:5";
+        let actual = Pos::new(4, 0, 4).to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// From<io::Error> must create an Io Error.
+    ///
+    /// Converting an io::Error with the `From` impl must produce an Io
+    /// variant carrying the source's message.
+    #[test]
+    fn error_from_io_error() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+        let expected = io_error.to_string();
+        let actual = Error::from(io_error);
+
+        match actual {
+            Error::Io { message, .. } => assert_eq!(expected, message),
+            _ => panic!("expected Error::Io"),
+        }
+    }
+
+    /// Error::fmt must display an Io Error.
+    ///
+    /// The Display trait must render a readable message for an Io Error.
+    #[test]
+    fn error_io_fmt() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+        let expected = "Error: I/O error: not found.";
+        let actual = Error::from(io_error).to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// StdError::source must return the wrapped io::Error.
+    ///
+    /// An Io Error's source must be the original io::Error it was converted
+    /// from, so the cause is preserved in the error chain.
+    #[test]
+    fn error_io_source() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+        let expected = io_error.to_string();
+        let error = Error::from(io_error);
+
+        let actual = StdError::source(&error).expect("expected a source").to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// From<ParseIntError> must create a ParseInt Error.
+    ///
+    /// Converting a ParseIntError with the `From` impl must produce a
+    /// ParseInt variant carrying the source's message.
+    #[test]
+    fn error_from_parse_int_error() {
+        let parse_error = "abc".parse::<i64>().unwrap_err();
+        let expected = parse_error.to_string();
+        let actual = Error::from(parse_error);
+
+        match actual {
+            Error::ParseInt { message, .. } => assert_eq!(expected, message),
+            _ => panic!("expected Error::ParseInt"),
+        }
+    }
+
+    /// StdError::source must return the wrapped ParseIntError.
+    ///
+    /// A ParseInt Error's source must be the original ParseIntError it was
+    /// converted from, so the cause is preserved in the error chain.
+    #[test]
+    fn error_parse_int_source() {
+        let parse_error = "abc".parse::<i64>().unwrap_err();
+        let expected = parse_error.to_string();
+        let error = Error::from(parse_error);
+
+        let actual = StdError::source(&error).expect("expected a source").to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// From<ParseFloatError> must create a ParseFloat Error.
+    ///
+    /// Converting a ParseFloatError with the `From` impl must produce a
+    /// ParseFloat variant carrying the source's message.
+    #[test]
+    fn error_from_parse_float_error() {
+        let parse_error = "abc".parse::<f64>().unwrap_err();
+        let expected = parse_error.to_string();
+        let actual = Error::from(parse_error);
+
+        match actual {
+            Error::ParseFloat { message, .. } => assert_eq!(expected, message),
+            _ => panic!("expected Error::ParseFloat"),
+        }
+    }
+
+    /// StdError::source must return the wrapped ParseFloatError.
+    ///
+    /// A ParseFloat Error's source must be the original ParseFloatError it
+    /// was converted from, so the cause is preserved in the error chain.
+    #[test]
+    fn error_parse_float_source() {
+        let parse_error = "abc".parse::<f64>().unwrap_err();
+        let expected = parse_error.to_string();
+        let error = Error::from(parse_error);
+
+        let actual = StdError::source(&error).expect("expected a source").to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// StdError::source must return None for variants without a cause.
+    ///
+    /// Every variant other than Io, ParseInt and ParseFloat originates
+    /// within this crate and must not report an underlying cause.
+    #[test]
+    fn error_source_none() {
+        let error = Error::new("Message");
+
+        assert!(StdError::source(&error).is_none());
+    }
 }
@@ -25,15 +25,62 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 mod action;
+mod completion;
 mod error;
+pub mod parser;
+mod repl;
 pub mod validate;
 
-pub use action::{Action, Argument, Field, Flag, Request};
-pub use error::{Error, Result};
+pub use action::{
+    Action, Argument, Field, FieldType, FilterOutcome, Flag, FromValue, Group, Request, Value,
+    ValueSource,
+};
+pub use error::{Error, Pos, Result};
+pub use repl::ReplOptions;
+use regex::Regex;
 use std::cmp::Eq;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::env::Args;
 use std::hash::Hash;
+use std::io::{self, BufRead, Write};
+use std::str::Chars;
+
+/// Translate a glob pattern into an anchored regular expression.
+///
+/// Walks `pattern` character by character, translating `*` to `.*` and `?`
+/// to `.`, regex-escaping every other character, and wrapping the result in
+/// `^...$` so a match covers the entire keyword rather than a substring of
+/// it.
+fn regex_from_glob(pattern: &str) -> String {
+    let mut regex = String::from("^");
+
+    for character in pattern.chars() {
+        match character {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            _ => regex.push_str(&regex::escape(&character.to_string())),
+        }
+    }
+
+    regex.push('$');
+    regex
+}
+
+/// Shell.
+///
+/// Identifies which shell's completion script format
+/// `Cherry::generate_completions` should produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    /// Generate a bash `complete -F` completion script.
+    Bash,
+    /// Generate a zsh `_arguments`/`compdef` completion script.
+    Zsh,
+    /// Generate a fish `complete -c` completion script.
+    Fish,
+    /// Generate a PowerShell `Register-ArgumentCompleter` completion script.
+    PowerShell,
+}
 
 /// Cherry.
 ///
@@ -56,10 +103,40 @@ use std::hash::Hash;
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug)]
 pub struct Cherry<T> {
     /// The available actions inserted into the Cherry instance.
     actions: HashMap<String, Action<T>>,
+
+    /// The environment map used for shell-style variable expansion in
+    /// parse_str, if enabled via with_expansion.
+    expansion: Option<HashMap<String, String>>,
+
+    /// Precompiled glob patterns for Actions registered under a wildcard
+    /// keyword (one containing `*` or `?`), alongside the literal keyword
+    /// they were registered under and their wildcard character count, used
+    /// to break ties deterministically when more than one pattern matches.
+    patterns: Vec<(String, Regex, usize)>,
+}
+
+impl<T> Eq for Cherry<T> {}
+
+impl<T> PartialEq for Cherry<T> {
+    /// Partial Equality implementation.
+    ///
+    /// Defines how Cherry instances should be considered equal. Compiled
+    /// glob patterns are derived from `actions`, so they are not compared
+    /// directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Cherry;
+    ///
+    /// assert_eq!(Cherry::<()>::new(), Cherry::<()>::new());
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.actions == other.actions && self.expansion == other.expansion
+    }
 }
 
 impl<T> Cherry<T> {
@@ -76,7 +153,39 @@ impl<T> Cherry<T> {
     pub fn new() -> Self {
         Cherry {
             actions: HashMap::new(),
+            expansion: None,
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Read a shell-style variable name following a `$` sigil.
+    ///
+    /// Supports both the bare `$NAME` form, consuming a run of alphanumeric
+    /// and underscore characters, and the braced `${NAME}` form, consuming
+    /// up to and including the closing brace. The leading `$` itself must
+    /// already have been consumed from `chars`.
+    fn read_variable_name(&self, chars: &mut Chars<'_>) -> String {
+        let mut name = String::new();
+
+        if chars.clone().next() == Some('{') {
+            chars.next();
+            for character in chars.by_ref() {
+                if character == '}' {
+                    break;
+                }
+                name.push(character);
+            }
+        } else {
+            while let Some(character) = chars.clone().next() {
+                if !character.is_alphanumeric() && character != '_' {
+                    break;
+                }
+                name.push(character);
+                chars.next();
+            }
         }
+
+        name
     }
 
     /// Escape the value.
@@ -108,7 +217,14 @@ impl<T> Cherry<T> {
     /// # Error
     /// Errors occur if attempting to insert an Action with a blank (empty)
     /// keyword. Will also error if a collision occurs when attempting to insert.
+    /// Will also error if the keyword contains `*` or `?` wildcards but does
+    /// not form a valid glob pattern.
     pub fn insert(mut self, action: Action<T>) -> Result<Self> {
+        debug_assert!({
+            action.assert();
+            true
+        });
+
         if action.keyword.is_empty() {
             return Err(Error::new("Action must have a non-empty keyword."));
         }
@@ -120,10 +236,145 @@ impl<T> Cherry<T> {
             )));
         }
 
+        if action.keyword.contains(['*', '?']) {
+            let wildcards = action
+                .keyword
+                .chars()
+                .filter(|character| matches!(character, '*' | '?'))
+                .count();
+            let regex = Regex::new(&regex_from_glob(&action.keyword))
+                .map_err(|error| Error::new(&error.to_string()))?;
+            self.patterns.push((action.keyword.clone(), regex, wildcards));
+        }
+
         self.actions.insert(action.keyword.clone(), action);
         Ok(self)
     }
 
+    /// Match a keyword against the registered glob patterns.
+    ///
+    /// Returns the Action registered under the pattern that matches
+    /// `keyword` with the fewest wildcard characters, breaking further ties
+    /// by insertion order. Returns `None` if no pattern matches.
+    fn match_pattern(&self, keyword: &str) -> Option<&Action<T>> {
+        self.patterns
+            .iter()
+            .filter(|(_, regex, _)| regex.is_match(keyword))
+            .min_by_key(|(_, _, wildcards)| *wildcards)
+            .and_then(|(pattern, ..)| self.actions.get(pattern))
+    }
+
+    /// Enable shell-style variable expansion in parse_str.
+    ///
+    /// Once enabled, `parse_str` expands `$NAME` and `${NAME}` references
+    /// against the supplied environment map, substituting an empty string
+    /// for any undefined variable. Expansion applies inside unquoted text
+    /// and double-quoted spans; single-quoted spans are left literal, and an
+    /// expanded value is never itself re-split on whitespace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Argument, Cherry};
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let mut env = HashMap::new();
+    ///     env.insert(String::from("ENV_REGION"), String::from("us-east-1"));
+    ///
+    ///     let cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("deploy")?.insert_argument(Argument::new("region")?)?)?
+    ///         .with_expansion(env);
+    ///
+    ///     let request = cherry.parse_str("deploy $ENV_REGION")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_expansion(mut self, env: HashMap<String, String>) -> Self {
+        self.expansion = Some(env);
+        self
+    }
+
+    /// Build the help text for the deepest Action matching the provided path.
+    ///
+    /// Walks `path` through the registered Actions for as long as a matching
+    /// keyword is found, then renders a clap-style usage block for the
+    /// deepest Action reached. An empty path, or a path whose first keyword
+    /// is not registered, renders the top-level listing of Actions instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("my_action")?)?;
+    ///     println!("{}", cherry.help(&["my_action"]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn help(&self, path: &[&str]) -> String {
+        match path.split_first().and_then(|(first, rest)| {
+            self.actions.get(*first).map(|action| (action, rest))
+        }) {
+            Some((action, rest)) => action.help(&[], rest),
+            None => self.help_root(),
+        }
+    }
+
+    /// Build the top-level help listing.
+    ///
+    /// Renders a usage line plus an ACTIONS section listing every Action
+    /// registered directly onto this Cherry instance.
+    fn help_root(&self) -> String {
+        let mut actions: Vec<&Action<T>> = self.actions.values().collect();
+        actions.sort();
+
+        let mut text = String::from("USAGE:\n    <action> [OPTIONS]\n");
+        if !actions.is_empty() {
+            text.push_str("\nACTIONS:\n");
+            for action in actions {
+                text.push_str(&format!(
+                    "    {:<20}{}\n",
+                    action.keyword,
+                    action.description_str().unwrap_or("")
+                ));
+            }
+        }
+        text.push_str("\nFor more information try '--help'.\n");
+        text
+    }
+
+    /// Generate a shell completion script.
+    ///
+    /// Recursively walks the registered Action tree to build a completion
+    /// script for the given `Shell`, offering child Action keywords and Field
+    /// and Flag long/short names at each level. The script is generated
+    /// directly from the live Action tree, so completions can never drift
+    /// from what `parse` actually accepts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry, Shell};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("my_action")?)?;
+    ///     println!("{}", cherry.generate_completions(Shell::Bash, "my_app"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn generate_completions(&self, shell: Shell, bin_name: &str) -> String {
+        let mut actions: Vec<&Action<T>> = self.actions.values().collect();
+        actions.sort();
+
+        match shell {
+            Shell::Bash => completion::bash(&actions, bin_name),
+            Shell::Zsh => completion::zsh(&actions, bin_name),
+            Shell::Fish => completion::fish(&actions, bin_name),
+            Shell::PowerShell => completion::powershell(&actions, bin_name),
+        }
+    }
+
     /// Parse the provided command.
     ///
     /// The parse command takes an Iterator of String types. This is parsed into
@@ -200,6 +451,44 @@ impl<T> Cherry<T> {
     /// }
     /// ```
     ///
+    /// ## Terminating Option Parsing
+    /// A bare `--` token stops Field/Flag detection (and child Action
+    /// selection); every token after it is routed straight to
+    /// `insert_argument`, allowing Argument values that begin with a hyphen.
+    /// ```rust
+    /// use cherry::{Action, Argument, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let mut cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("my_action")?.insert_argument(Argument::new("path")?)?)?;
+    ///
+    ///     let args = ["my_action", "--", "-weird"].into_iter();
+    ///     let request = cherry.parse(args)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ## Built-in Help Flag
+    /// A bare `--help` or `-h` token, wherever it appears, immediately stops
+    /// parsing and returns the help text for the deepest Action reached so
+    /// far, unless the current Action already declares its own `help`
+    /// Flag, in which case the built-in is not checked. A `help` Field does
+    /// not turn the built-in off.
+    /// ```rust
+    /// use cherry::{Action, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let mut cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("my_action")?)?;
+    ///
+    ///     let error = cherry.parse(["my_action", "--help"].into_iter()).unwrap_err();
+    ///     assert!(error.to_string().contains("USAGE:"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// # Error
     /// Will error if:
     ///
@@ -219,28 +508,61 @@ impl<T> Cherry<T> {
         C: Iterator<Item = D>,
         D: AsRef<str> + Eq + Hash,
     {
+        // Tracks the keywords of the Action path successfully located so far,
+        // so an error can carry the help text for the deepest match.
+        let mut path: Vec<String> = Vec::new();
+        let help = |path: &[String]| -> Error {
+            let path: Vec<&str> = path.iter().map(String::as_str).collect();
+            Error::new(&self.help(&path))
+        };
+
         // Select the Action.
         let keyword = self.escape(
             command
                 .next()
-                .ok_or_else(|| Error::new("Todo: Help."))?
+                .ok_or_else(|| help(&path))?
                 .as_ref(),
         );
-        let mut action = self
-            .actions
-            .get(&keyword)
-            .ok_or_else(|| Error::new("Todo: Help."))?;
+        if keyword == "--help" || keyword == "-h" {
+            return Err(help(&path));
+        }
+        let mut action = match self.actions.get(&keyword) {
+            Some(action) => action,
+            None => self.match_pattern(&keyword).ok_or_else(|| {
+                Error::unknown_action(&keyword, self.actions.keys().map(String::as_str))
+            })?,
+        };
+        path.push(keyword);
         let mut request = Request::new(action);
         let mut child_path = true;
+        let mut terminated = false;
 
         // Parse Child Actions, Arguments, Fields and Flags.
         while let Some(next) = command.next() {
             let value = self.escape(next.as_ref());
 
+            if terminated {
+                request = request.insert_argument(&value).map_err(|_| help(&path))?;
+                continue;
+            }
+
+            if value == "--" {
+                terminated = true;
+                child_path = false;
+                continue;
+            }
+
+            if (value == "--help" || value == "-h") && !request.has_flag("help") {
+                return Err(help(&path));
+            }
+
             if child_path {
                 let child = action.get_child(&value);
                 match child {
-                    Some(child) => action = child,
+                    Some(child) => {
+                        action = child;
+                        path.push(value.clone());
+                    }
                     None => child_path = false
                 }
                 request = Request::new(action);
@@ -260,24 +582,132 @@ impl<T> Cherry<T> {
                         r.insert_flag(&String::from_utf8_lossy(&[byte]))
                     })?
                 } else if request.has_flag(stripped) {
-                    request.insert_flag(stripped)?
+                    request.insert_flag(stripped).map_err(|_| help(&path))?
                 } else {
                     let field_value = command.next().map_or_else(
-                        || Err(Error::new("Todo: Help.")),
+                        || Err(help(&path)),
                         |value| Ok(self.escape(value.as_ref())),
                     )?;
-                    request.insert_field(stripped, &field_value)?
+                    request
+                        .insert_field(stripped, &field_value)
+                        .map_err(|_| help(&path))?
                 }
             } else {
-                request.insert_argument(&value)?
+                request.insert_argument(&value).map_err(|_| help(&path))?
             };
         }
 
         // Validate the Request.
-        match request.validate() {
-            true => Ok(request),
-            false => Err(Error::new("Todo: Help.")),
+        request
+            .validate()
+            .map_err(|messages| Error::validation_failed(&messages))?;
+        request.validate_groups()?;
+
+        Ok(request)
+    }
+
+    /// Parse and run a command, running prerequisite Actions first.
+    ///
+    /// Parses `command` as per `parse`, then walks the matched Action's
+    /// `requires` declarations to build a dependency order, running each
+    /// prerequisite's callback (discarding its result) before running the
+    /// matched Action itself and returning its result. Prerequisites are
+    /// resolved against the Actions registered directly on this Cherry
+    /// instance, not nested child Actions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(Action::new("build")?.then(|_| ()))?
+    ///         .insert(Action::new("deploy")?.requires("build").then(|_| ()))?;
+    ///
+    ///     cherry.dispatch(["deploy"].into_iter())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Error
+    /// Will error if:
+    ///
+    /// * The underlying call to `parse` errors.
+    /// * A declared prerequisite is not a registered Action.
+    /// * The prerequisite graph contains a cycle.
+    pub fn dispatch<C, D>(&self, command: C) -> Result<T>
+    where
+        C: Iterator<Item = D>,
+        D: AsRef<str> + Eq + Hash,
+    {
+        let request = self.parse(command)?;
+
+        for keyword in self.prerequisite_order(request.keyword())? {
+            let action = self.actions.get(&keyword).ok_or_else(|| {
+                Error::new(&format!(
+                    "Prerequisite '{keyword}' is not a registered Action."
+                ))
+            })?;
+            Request::new(action).run()?;
         }
+
+        request.run()
+    }
+
+    /// Build a run order for a keyword's prerequisite chain.
+    ///
+    /// Performs a depth-first post-order traversal over `requires`
+    /// declarations, so every prerequisite appears before the Action(s) that
+    /// depend on it. The keyword itself is not included in the returned
+    /// order.
+    fn prerequisite_order(&self, keyword: &str) -> Result<Vec<String>> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        let mut visiting = HashSet::new();
+        self.visit_prerequisites(keyword, &mut order, &mut visited, &mut visiting)?;
+        Ok(order)
+    }
+
+    /// Depth-first helper for `prerequisite_order`.
+    ///
+    /// Visits `keyword`'s prerequisites depth-first, appending each to
+    /// `order` only once all of its own prerequisites have been appended.
+    /// `visiting` tracks the current traversal stack to detect cycles;
+    /// `visited` tracks keywords whose prerequisites have already been fully
+    /// resolved, to avoid repeating work.
+    fn visit_prerequisites(
+        &self,
+        keyword: &str,
+        order: &mut Vec<String>,
+        visited: &mut HashSet<String>,
+        visiting: &mut HashSet<String>,
+    ) -> Result<()> {
+        if visited.contains(keyword) {
+            return Ok(());
+        }
+
+        if !visiting.insert(String::from(keyword)) {
+            return Err(Error::new(&format!(
+                "Prerequisite cycle detected at '{keyword}'."
+            )));
+        }
+
+        let action = self.actions.get(keyword).ok_or_else(|| {
+            Error::new(&format!(
+                "Prerequisite '{keyword}' is not a registered Action."
+            ))
+        })?;
+
+        for prerequisite in action.prerequisites() {
+            self.visit_prerequisites(prerequisite, order, visited, visiting)?;
+            if !order.contains(prerequisite) {
+                order.push(prerequisite.clone());
+            }
+        }
+
+        visiting.remove(keyword);
+        visited.insert(String::from(keyword));
+        Ok(())
     }
 
     /// Load the command into Cherry from command line arguments.
@@ -379,7 +809,11 @@ impl<T> Cherry<T> {
     /// Supports using both single and double quotation marks to capture whitespace
     /// within a value. Also supports escaping both quotation styles, hyphens and
     /// backslashes. Note that the non-enclosing quotation style does not have to
-    /// be escaped, however, can be.
+    /// be escaped, however, can be. A bare fragment directly followed by a
+    /// quoted span, and quoted spans directly next to each other, concatenate
+    /// into a single token, e.g. `foo'bar'"baz"` parses as one argument
+    /// `foobarbaz`. A bare character directly after a closing quote is a
+    /// syntax error.
     ///
     /// # Example
     /// ## Parse from a string
@@ -413,6 +847,24 @@ impl<T> Cherry<T> {
     /// }
     /// ```
     ///
+    /// ## Adjacent Quotes
+    /// ```rust
+    /// use cherry::{Action, Argument, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let mut cherry = Cherry::<()>::new()
+    ///         .insert(
+    ///             Action::new("my_action")?
+    ///                 .insert_argument(Argument::new("one")?)?
+    ///         )?;
+    ///
+    ///     // Concatenates into the single argument "foobarbaz".
+    ///     let request = cherry.parse_str("my_action foo'bar'\"baz\"")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// ## Escaping Special Characters
     /// ```rust
     /// use cherry::{Action, Cherry};
@@ -420,7 +872,7 @@ impl<T> Cherry<T> {
     /// fn main() -> cherry::Result<()> {
     ///     let mut cherry = Cherry::<()>::new()
     ///         .insert(Action::new("my_action \'\"-\\")?)?;
-    ///     let request = cherry.parse_str("\"my_action \\\'\\\"-\\\\")?;
+    ///     let request = cherry.parse_str("\"my_action \\\'\\\"\\-\\\\\"")?;
     ///     Ok(())
     /// }
     /// ```
@@ -431,18 +883,64 @@ impl<T> Cherry<T> {
         let mut chars = command.chars();
         let mut parts = Vec::new();
         let mut build = String::new();
+        let mut has_token = false;
         let mut quote = None;
         let mut old_quote = None;
+        let mut quote_start = None;
+        let mut pos = Pos::new(0, 0, 0);
         while let Some(mut character) = chars.next() {
-            if old_quote.is_some() && quote.is_none() && !character.is_whitespace() {
-                return Err(Error::new("Todo: Help."));
+            // The position of the character read above, before it (and any
+            // character it pulls along with it, such as an escaped
+            // character) is folded into `pos` below. Error sites below
+            // report this position, as it is where the problem was found.
+            let char_pos = pos;
+            pos.offset += character.len_utf8();
+            if character == '\n' {
+                pos.line += 1;
+                pos.column = 0;
+            } else {
+                pos.column += 1;
+            }
+
+            if old_quote.is_some()
+                && quote.is_none()
+                && !character.is_whitespace()
+                && character != '\''
+                && character != '"'
+            {
+                return Err(Error::syntax(
+                    "a quote must be surrounded by whitespace",
+                    char_pos,
+                    None,
+                ));
+            }
+
+            if character == '$' && quote != Some('\'') {
+                if let Some(env) = &self.expansion {
+                    let name = self.read_variable_name(&mut chars);
+                    pos.offset += name.len();
+                    pos.column += name.chars().count();
+                    let value = env.get(&name).map_or("", String::as_str);
+                    build.push_str(value);
+                    has_token = true;
+                    old_quote = quote;
+                    continue;
+                }
             }
 
             (old_quote, quote) = (
                 quote,
                 match character {
                     '\\' => {
-                        character = chars.next().ok_or_else(|| Error::new("Todo: Help."))?;
+                        character = chars.next().ok_or_else(|| {
+                            Error::syntax(
+                                "dangling escape with nothing to escape",
+                                char_pos,
+                                None,
+                            )
+                        })?;
+                        pos.offset += character.len_utf8();
+                        pos.column += 1;
                         build.push('\\');
                         quote
                     }
@@ -454,10 +952,26 @@ impl<T> Cherry<T> {
                 },
             );
 
-            if old_quote != quote || quote.is_none() && character.is_whitespace() {
-                if !build.is_empty() {
+            if old_quote != quote {
+                // A quote delimiter just opened or closed; consume it
+                // without adding it to `build` and without flushing, so
+                // that adjacent quoted and bare fragments (e.g.
+                // `foo'bar'"baz"`) concatenate into a single token instead
+                // of splitting at the boundary. Opening a quote still marks
+                // a token as started, so an empty quoted string (e.g. `""`)
+                // is kept rather than discarded as if nothing were there.
+                if old_quote.is_none() {
+                    has_token = true;
+                    quote_start = Some(char_pos);
+                }
+                continue;
+            }
+
+            if quote.is_none() && character.is_whitespace() {
+                if !build.is_empty() || has_token {
                     parts.push(build);
                     build = String::new();
+                    has_token = false;
                 }
                 continue;
             }
@@ -465,80 +979,342 @@ impl<T> Cherry<T> {
             build.push(character);
         }
 
-        if !build.is_empty() {
+        if let (Some(start), Some(quote)) = (quote_start, quote) {
+            return Err(Error::syntax(
+                &format!("unterminated {quote} quote"),
+                start,
+                Some(pos),
+            ));
+        }
+
+        if !build.is_empty() || has_token {
             parts.push(build);
         }
 
         self.parse(parts.into_iter())
     }
-}
 
-impl Default for Cherry<()> {
-    /// Create a new Cherry.
+    /// Run an interactive read-eval-print loop.
     ///
-    /// Create a new Cherry instance. Note that this is identical to the new
-    /// method.
+    /// Reads lines from stdin, one at a time, parsing each with `parse_str`
+    /// and running the located Action's callback. A line that fails to parse
+    /// prints the resulting Error instead of aborting the loop. The sentinels
+    /// `exit` and `quit` end the loop. Equivalent to calling `repl_with` with
+    /// stdin, stdout, and no prompt.
     ///
     /// # Example
-    /// ```rust
-    /// use cherry::Cherry;
+    /// ```rust,no_run
+    /// use cherry::{Action, Cherry};
     ///
-    /// let cherry = Cherry::default();
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(Action::new("greet")?.then(|_request| println!("Hello!")))?;
+    ///
+    ///     cherry.repl();
+    ///
+    ///     Ok(())
+    /// }
     /// ```
-    fn default() -> Self {
-        Self::new()
+    pub fn repl(&self) {
+        self.repl_with(io::stdin().lock(), io::stdout(), None);
     }
-}
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
 
-    /// Cherry::new must create as per struct initialisation.
+    /// Run an interactive read-eval-print loop over the given reader and writer.
     ///
-    /// The new method on Cherry must create an object as per the struct
-    /// initialiser syntax.
-    #[test]
-    fn cherry_new() {
-        let expected = Cherry {
-            actions: HashMap::new(),
-        };
-        let actual = Cherry::<()>::new();
-        assert_eq!(expected, actual);
-    }
-
-    /// Cherry::default must create as per struct initialisation.
+    /// Behaves as `repl`, but reads lines from `reader` and writes help text
+    /// and prompts to `writer`, allowing both to be swapped out (for example,
+    /// in tests). When `prompt` is set, it is written before each line is
+    /// read.
     ///
-    /// The default method on Cherry must create an object as per the struct
-    /// initialiser syntax.
-    #[test]
-    fn cherry_default() {
-        let expected = Cherry {
-            actions: HashMap::new(),
-        };
-        let actual = Cherry::<()>::default();
-        assert_eq!(expected, actual);
-    }
-
-    /// Cherry::parse must correctly parse a Request.
+    /// # Example
+    /// ```rust,no_run
+    /// use cherry::{Action, Cherry};
+    /// use std::io;
     ///
-    /// The parse method must correctly parse a Request, linked to the correctly
-    /// selected Action type.
-    #[test]
-    fn cherry_parse() {
-        let cherry = Cherry::<()>::new()
-            .insert(Action::new("my_action").unwrap())
-            .unwrap();
-
-        let expected = Request::new(&cherry.actions.get("my_action").unwrap());
-
-        let actual = cherry.parse(["my_action"].into_iter()).unwrap();
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(Action::new("greet")?.then(|_request| println!("Hello!")))?;
+    ///
+    ///     cherry.repl_with(io::stdin().lock(), io::stdout(), Some("> "));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn repl_with<R: BufRead, W: Write>(&self, reader: R, writer: W, prompt: Option<&str>) {
+        let mut options = ReplOptions::new();
+        if let Some(prompt) = prompt {
+            options = options.prompt(prompt);
+        }
 
-        assert_eq!(expected, actual);
+        self.repl_with_handler(reader, writer, &options, |_| ());
     }
 
-    /// Cherry::parse must correctly parse a Request with escapes.
+    /// Run an interactive read-eval-print loop with history and a result handler.
+    ///
+    /// Behaves as `repl_with`, reading lines from `reader` and writing help
+    /// text and prompts to `writer`, but additionally:
+    ///
+    /// * Loads persistent line history from `options`'s history file (if
+    ///   set) before the loop starts, and appends each entered line other
+    ///   than a meta-command or sentinel to it before parsing it, whether or
+    ///   not it then parses.
+    /// * Recognises the meta-commands `:help` (prints this Cherry's
+    ///   top-level usage block), `:quit` (ends the loop), and `:history`
+    ///   (prints the in-memory ring of recently entered lines), alongside
+    ///   the existing bare `exit`/`quit` sentinels. The leading `:` keeps
+    ///   these from colliding with a registered Action keyword.
+    /// * Spans a command across more than one read when a line ends with an
+    ///   unterminated quote or a trailing, unescaped backslash, writing
+    ///   `options`'s continuation prompt before each extra read, per
+    ///   `repl::continuation`.
+    /// * Calls `handler` with the value returned by a successfully run
+    ///   Action, instead of discarding it.
+    ///
+    /// Tab-completion candidates for a given in-progress line can be
+    /// retrieved separately via `repl_complete`, for embedding applications
+    /// that drive their own line editor.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use cherry::{Action, Cherry, ReplOptions};
+    /// use std::io;
+    /// use std::path::Path;
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::new()
+    ///         .insert(Action::new("greet")?.then(|_request| String::from("Hello!")))?;
+    ///
+    ///     let options = ReplOptions::new()
+    ///         .prompt("> ")
+    ///         .history(Path::new(".greet_history"));
+    ///     cherry.repl_with_handler(io::stdin().lock(), io::stdout(), &options, |message| {
+    ///         println!("{message}");
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn repl_with_handler<R: BufRead, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        options: &ReplOptions,
+        handler: impl Fn(T),
+    ) {
+        if let Some(path) = options.history_path() {
+            let history = repl::load_history(path);
+            if !history.is_empty() {
+                let _ = writeln!(
+                    writer,
+                    "Loaded {} line(s) of history from {}.",
+                    history.len(),
+                    path.display()
+                );
+            }
+        }
+        let mut line = String::new();
+        let mut ring = VecDeque::new();
+
+        'outer: loop {
+            if let Some(prompt) = options.prompt_str() {
+                let _ = write!(writer, "{prompt}");
+                let _ = writer.flush();
+            }
+
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            let mut command = String::from(line.trim_end_matches(['\n', '\r']));
+            while let Some(joiner) = repl::continuation(&command) {
+                if joiner == " " {
+                    command.pop();
+                }
+                if let Some(prompt) = options.continuation_prompt_str() {
+                    let _ = write!(writer, "{prompt}");
+                    let _ = writer.flush();
+                }
+
+                line.clear();
+                match reader.read_line(&mut line) {
+                    Ok(0) | Err(_) => break 'outer,
+                    Ok(_) => {}
+                }
+                command.push_str(joiner);
+                command.push_str(line.trim_end_matches(['\n', '\r']));
+            }
+
+            let command = command.trim();
+            if command.is_empty() {
+                continue;
+            }
+            if command == "exit" || command == "quit" || command == ":quit" {
+                break;
+            }
+            if command == ":help" {
+                let _ = writeln!(writer, "{}", self.help(&[]));
+                continue;
+            }
+            if command == ":history" {
+                for entry in &ring {
+                    let _ = writeln!(writer, "{entry}");
+                }
+                continue;
+            }
+
+            if let Some(path) = options.history_path() {
+                repl::append_history(path, command);
+            }
+            repl::push_ring(&mut ring, command);
+
+            match self.parse_str(command) {
+                Ok(request) => match request.run() {
+                    Ok(value) => handler(value),
+                    Err(error) => {
+                        let _ = writeln!(writer, "{error}");
+                    }
+                },
+                Err(error) => {
+                    let _ = writeln!(writer, "{error}");
+                }
+            }
+        }
+    }
+
+    /// Suggest tab-completion candidates for an in-progress REPL line.
+    ///
+    /// Splits `line` into whitespace-separated tokens, walks the Action tree
+    /// by the same child-keyword traversal `parse` uses, and returns every
+    /// completion word (child Action keywords, and Field/Flag long and short
+    /// names) reachable from that point which starts with the final,
+    /// in-progress token. Embedding applications driving their own line
+    /// editor call this to implement tab-completion; `repl_with_handler`
+    /// itself only reads whole lines, so it does not call this directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::{Action, Cherry};
+    ///
+    /// fn main() -> cherry::Result<()> {
+    ///     let cherry = Cherry::<()>::new()
+    ///         .insert(Action::new("greet")?)?
+    ///         .insert(Action::new("goodbye")?)?;
+    ///
+    ///     assert_eq!(vec![String::from("greet")], cherry.repl_complete("gre"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn repl_complete(&self, line: &str) -> Vec<String> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let ends_in_whitespace = line.ends_with(char::is_whitespace);
+        let (path, partial) = match tokens.split_last() {
+            Some((&last, rest)) if !ends_in_whitespace => (rest, last),
+            _ => (tokens.as_slice(), ""),
+        };
+
+        let Some((&keyword, rest)) = path.split_first() else {
+            return self
+                .actions
+                .keys()
+                .filter(|candidate| candidate.starts_with(partial))
+                .cloned()
+                .collect();
+        };
+
+        let mut action = match self.actions.get(keyword) {
+            Some(action) => action,
+            None => return Vec::new(),
+        };
+        for keyword in rest {
+            action = match action.get_child(keyword) {
+                Some(child) => child,
+                None => return Vec::new(),
+            };
+        }
+
+        action
+            .completion_words()
+            .into_iter()
+            .filter(|word| word.starts_with(partial))
+            .collect()
+    }
+}
+
+impl Default for Cherry<()> {
+    /// Create a new Cherry.
+    ///
+    /// Create a new Cherry instance. Note that this is identical to the new
+    /// method.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::Cherry;
+    ///
+    /// let cherry = Cherry::default();
+    /// ```
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::io::Cursor;
+    use std::rc::Rc;
+
+    /// Cherry::new must create as per struct initialisation.
+    ///
+    /// The new method on Cherry must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn cherry_new() {
+        let expected = Cherry {
+            actions: HashMap::new(),
+            expansion: None,
+            patterns: Vec::new(),
+        };
+        let actual = Cherry::<()>::new();
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::default must create as per struct initialisation.
+    ///
+    /// The default method on Cherry must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn cherry_default() {
+        let expected = Cherry {
+            actions: HashMap::new(),
+            expansion: None,
+            patterns: Vec::new(),
+        };
+        let actual = Cherry::<()>::default();
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must correctly parse a Request.
+    ///
+    /// The parse method must correctly parse a Request, linked to the correctly
+    /// selected Action type.
+    #[test]
+    fn cherry_parse() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap());
+
+        let actual = cherry.parse(["my_action"].into_iter()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must correctly parse a Request with escapes.
     ///
     /// The parse method must correctly parse a Request, linked to the correctly
     /// selected Action type, correctly escaping special characters.
@@ -562,10 +1338,31 @@ mod tests {
     /// object.
     #[test]
     fn cherry_parse_empty_actions() {
-        let expected = Error::new("Todo: Help.");
-        let actual = Cherry::<()>::new()
-            .parse(["my_action"].into_iter())
-            .unwrap_err();
+        let cherry = Cherry::<()>::new();
+        let expected = Error::UnknownAction {
+            input: String::from("my_action"),
+            suggestion: None,
+        };
+        let actual = cherry.parse(["my_action"].into_iter()).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must suggest the closest Action when unrecognised.
+    ///
+    /// The parse method must populate the UnknownAction Error's suggestion
+    /// with the registered Action keyword closest to the requested input.
+    #[test]
+    fn cherry_parse_unknown_action_suggestion() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::UnknownAction {
+            input: String::from("my_acton"),
+            suggestion: Some(String::from("my_action")),
+        };
+        let actual = cherry.parse(["my_acton"].into_iter()).unwrap_err();
 
         assert_eq!(expected, actual);
     }
@@ -577,12 +1374,11 @@ mod tests {
     #[test]
     fn cherry_parse_empty_command() {
         let args: [&str; 0] = [];
-        let expected = Error::new("Todo: Help.");
-        let actual = Cherry::<()>::new()
+        let cherry = Cherry::<()>::new()
             .insert(Action::new("my_action").unwrap())
-            .unwrap()
-            .parse(args.into_iter())
-            .unwrap_err();
+            .unwrap();
+        let expected = Error::new(&cherry.help(&[]));
+        let actual = cherry.parse(args.into_iter()).unwrap_err();
 
         assert_eq!(expected, actual);
     }
@@ -726,6 +1522,157 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Cherry::parse must treat tokens after `--` as Arguments.
+    ///
+    /// The parse method must route every token following a bare `--`
+    /// terminator through insert_argument, even if it begins with a hyphen.
+    #[test]
+    fn cherry_parse_terminator() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("-notaflag")
+            .unwrap();
+        let actual = cherry
+            .parse(["my_action", "--", "-notaflag"].into_iter())
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must not itself push the `--` terminator as an Argument.
+    ///
+    /// The parse method must consume the bare `--` terminator token without
+    /// routing it to insert_argument.
+    #[test]
+    fn cherry_parse_terminator_not_pushed() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("value")
+            .unwrap();
+        let actual = cherry
+            .parse(["my_action", "--", "value"].into_iter())
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must end child Action selection at the `--` terminator.
+    ///
+    /// The parse method must stop matching tokens after `--` against child
+    /// Actions, routing them to insert_argument on the located Action instead.
+    #[test]
+    fn cherry_parse_terminator_ends_child_path() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap()
+                    .insert_child(Action::new("my_child").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("my_child")
+            .unwrap();
+        let actual = cherry
+            .parse(["my_action", "--", "my_child"].into_iter())
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must return help text on a bare `--help` token.
+    ///
+    /// The parse method must stop parsing and return an Error carrying the
+    /// located Action's help text when `--help` is encountered.
+    #[test]
+    fn cherry_parse_help_flag() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::new(&cherry.help(&["my_action"]));
+        let actual = cherry
+            .parse(["my_action", "--help"].into_iter())
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must return help text on a bare `-h` token.
+    ///
+    /// The parse method must recognise the short form of the built-in help
+    /// flag identically to `--help`.
+    #[test]
+    fn cherry_parse_help_flag_short() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::new(&cherry.help(&["my_action"]));
+        let actual = cherry.parse(["my_action", "-h"].into_iter()).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must return root help text for a bare `--help` keyword.
+    ///
+    /// The parse method must treat `--help` given as the first token (before
+    /// any Action is selected) as a request for the top-level help text.
+    #[test]
+    fn cherry_parse_help_flag_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::new(&cherry.help(&[]));
+        let actual = cherry.parse(["--help"].into_iter()).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must not intercept `--help` as the built-in flag when
+    /// the Action declares its own `help` Flag.
+    ///
+    /// The parse method must defer to a user-declared `help` Flag instead of
+    /// short-circuiting to the built-in help text.
+    #[test]
+    fn cherry_parse_help_flag_overridden() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_flag(Flag::new("help").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_flag("help")
+            .unwrap();
+        let actual = cherry.parse(["my_action", "--help"].into_iter()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
     /// Cherry::parse must error if too many Arguments supplied.
     ///
     /// The parse method error if too many Arguments supplied to the Action.
@@ -740,7 +1687,7 @@ mod tests {
             )
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
+        let expected = Error::new(&cherry.help(&["my_action"]));
         let actual = cherry
             .parse(["my_action", "first", "second"].into_iter())
             .unwrap_err();
@@ -762,7 +1709,9 @@ mod tests {
             )
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
+        let expected = Error::validation_failed(&[String::from(
+            "Expected 1 argument(s), found 0.",
+        )]);
         let actual = cherry.parse(["my_action"].into_iter()).unwrap_err();
 
         assert_eq!(expected, actual);
@@ -833,7 +1782,7 @@ mod tests {
             )
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
+        let expected = Error::new(&cherry.help(&["my_action"]));
         let actual = cherry
             .parse(["my_action", "--my_field"].into_iter())
             .unwrap_err();
@@ -866,6 +1815,41 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Cherry::parse must reject a Group's mutual exclusion violation.
+    ///
+    /// The parse method must return a GroupViolation Error when more than one
+    /// of a non-multiple Group's Fields is supplied.
+    #[test]
+    fn cherry_parse_group_conflict() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("json").unwrap())
+                    .unwrap()
+                    .insert_field(Field::new("yaml").unwrap())
+                    .unwrap()
+                    .insert_group(
+                        Group::new("format")
+                            .unwrap()
+                            .args(&["json", "yaml"])
+                            .multiple(false),
+                    )
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Error::group_violation(
+            "format",
+            "argument '--json' cannot be used with '--yaml'",
+        );
+        let actual = cherry
+            .parse(["my_action", "--json", "a", "--yaml", "b"].into_iter())
+            .unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
     /// Cherry::parse must correctly parse short Flags.
     ///
     /// The parse method must correctly parse a Request, linked to the correctly
@@ -932,7 +1916,7 @@ mod tests {
             )
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
+        let expected = Error::new(&cherry.help(&["my_action"]));
         let actual = cherry
             .parse(["my_action", "--not_my_flag"].into_iter())
             .unwrap_err();
@@ -978,12 +1962,14 @@ mod tests {
     /// the Cherry object.
     #[test]
     fn cherry_parse_slice_empty_command() {
-        let expected = Error::new("Todo: Help.");
-        let actual = Cherry::<()>::new()
+        let cherry = Cherry::<()>::new()
             .insert(Action::new("my_action").unwrap())
-            .unwrap()
-            .parse_slice(&[""])
-            .unwrap_err();
+            .unwrap();
+        let expected = Error::UnknownAction {
+            input: String::from(""),
+            suggestion: None,
+        };
+        let actual = cherry.parse_slice(&[""]).unwrap_err();
 
         assert_eq!(expected, actual);
     }
@@ -1019,6 +2005,29 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Cherry::parse_str must concatenate adjacent quoted and bare fragments.
+    ///
+    /// The parse_str method must treat a bare word immediately followed by a
+    /// quoted span, with no separating whitespace, as a single token.
+    #[test]
+    fn cherry_parse_str_adjacent_quotes() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("one").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("foobarbaz")
+            .unwrap();
+        let actual = cherry.parse_str("my_action foo'bar'\"baz\"").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
     /// Cherry::parse_str must correctly parse a Request including quotes.
     ///
     /// The parse_str method must correctly handle internal quotes when using quotes.
@@ -1065,18 +2074,172 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    /// Cherry::parse_str must not expand variables when disabled.
+    ///
+    /// The parse_str method must leave `$NAME` references untouched unless
+    /// with_expansion has been called.
+    #[test]
+    fn cherry_parse_str_expansion_disabled() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("$MY_VAR")
+            .unwrap();
+        let actual = cherry.parse_str("my_action $MY_VAR").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must expand bare `$NAME` variables.
+    ///
+    /// The parse_str method must substitute a bare `$NAME` reference with its
+    /// value from the environment map passed to with_expansion.
+    #[test]
+    fn cherry_parse_str_expansion_bare() {
+        let mut env = HashMap::new();
+        env.insert(String::from("MY_VAR"), String::from("my_value"));
+
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_expansion(env);
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("my_value")
+            .unwrap();
+        let actual = cherry.parse_str("my_action $MY_VAR").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must expand braced `${NAME}` variables.
+    ///
+    /// The parse_str method must substitute a braced `${NAME}` reference with
+    /// its value from the environment map passed to with_expansion.
+    #[test]
+    fn cherry_parse_str_expansion_braced() {
+        let mut env = HashMap::new();
+        env.insert(String::from("MY_VAR"), String::from("my_value"));
+
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_expansion(env);
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("prefix_my_value")
+            .unwrap();
+        let actual = cherry.parse_str("my_action prefix_${MY_VAR}").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must expand undefined variables to an empty string.
+    ///
+    /// The parse_str method must substitute an empty string for any `$NAME`
+    /// reference not present in the environment map.
+    #[test]
+    fn cherry_parse_str_expansion_undefined() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_expansion(HashMap::new());
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("")
+            .unwrap();
+        let actual = cherry.parse_str("my_action $MY_VAR").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must not expand variables inside single quotes.
+    ///
+    /// The parse_str method must treat `$NAME` as a literal inside a
+    /// single-quoted span, even when expansion is enabled.
+    #[test]
+    fn cherry_parse_str_expansion_single_quote_literal() {
+        let mut env = HashMap::new();
+        env.insert(String::from("MY_VAR"), String::from("my_value"));
+
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_expansion(env);
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("$MY_VAR")
+            .unwrap();
+        let actual = cherry.parse_str("my_action '$MY_VAR'").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must not re-split an expanded value on whitespace.
+    ///
+    /// The parse_str method must keep a single expanded `$NAME` reference as
+    /// one argument, even when the substituted value contains whitespace.
+    #[test]
+    fn cherry_parse_str_expansion_not_resplit() {
+        let mut env = HashMap::new();
+        env.insert(String::from("MY_VAR"), String::from("my value"));
+
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_argument(Argument::new("my_argument").unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_expansion(env);
+
+        let expected = Request::new(&cherry.actions.get("my_action").unwrap())
+            .insert_argument("my value")
+            .unwrap();
+        let actual = cherry.parse_str("my_action $MY_VAR").unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
     /// Cherry::parse_str must error when no command.
     ///
     /// The parse_str method must error when no command is provided when parsing the
     /// Cherry object.
     #[test]
     fn cherry_parse_str_empty_command() {
-        let expected = Error::new("Todo: Help.");
-        let actual = Cherry::<()>::new()
+        let cherry = Cherry::<()>::new()
             .insert(Action::new("my_action").unwrap())
-            .unwrap()
-            .parse_str("")
-            .unwrap_err();
+            .unwrap();
+        let expected = Error::new(&cherry.help(&[]));
+        let actual = cherry.parse_str("").unwrap_err();
 
         assert_eq!(expected, actual);
     }
@@ -1096,12 +2259,56 @@ mod tests {
             )
             .unwrap();
 
-        let expected = Error::new("Todo: Help.");
+        let expected = Error::syntax(
+            "a quote must be surrounded by whitespace",
+            Pos::new(11, 0, 11),
+            None,
+        );
         let actual = cherry.parse_str("'my action'value").unwrap_err();
 
         assert_eq!(expected, actual);
     }
 
+    /// Cherry::parse_str must error with a syntax error on a dangling escape.
+    ///
+    /// The parse_str method must error with a SyntaxError positioned at the
+    /// trailing backslash when it has no following character to escape.
+    #[test]
+    fn cherry_parse_str_dangling_escape() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::syntax(
+            "dangling escape with nothing to escape",
+            Pos::new(10, 0, 10),
+            None,
+        );
+        let actual = cherry.parse_str("my_action \\").unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse_str must error with a syntax error on an unterminated quote.
+    ///
+    /// The parse_str method must error with a SyntaxError, positioned at the
+    /// quote that was opened, when the command ends before it is closed.
+    #[test]
+    fn cherry_parse_str_unterminated_quote() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let expected = Error::syntax(
+            "unterminated \" quote",
+            Pos::new(10, 0, 10),
+            Some(Pos::new(23, 0, 23)),
+        );
+        let actual = cherry.parse_str("my_action \"unterminated").unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
     /// Cherry::insert must insert an Action.
     ///
     /// The insert method must correctly insert an Action into the internal
@@ -1156,4 +2363,733 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    /// Cherry::insert must compile a glob pattern for wildcard keywords.
+    ///
+    /// The insert method must precompile a Regex for any keyword containing
+    /// `*` or `?`, and register it so that Cherry::parse can match it.
+    #[test]
+    fn cherry_insert_glob() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("git:*").unwrap())
+            .unwrap();
+
+        assert_eq!(1, cherry.patterns.len());
+        assert_eq!("git:*", cherry.patterns[0].0);
+        assert_eq!(1, cherry.patterns[0].2);
+    }
+
+    /// regex_from_glob must translate wildcards and escape metacharacters.
+    ///
+    /// The regex_from_glob function must translate `*` to `.*` and `?` to
+    /// `.`, escape other regex metacharacters, and anchor the result.
+    #[test]
+    fn regex_from_glob_translates() {
+        let expected = r"^git:.*$";
+        let actual = regex_from_glob("git:*");
+        assert_eq!(expected, actual);
+    }
+
+    /// regex_from_glob must escape regex metacharacters.
+    ///
+    /// The regex_from_glob function must escape characters with special
+    /// meaning in a regular expression, such as `.`.
+    #[test]
+    fn regex_from_glob_escapes_metacharacters() {
+        let expected = r"^log\.txt$";
+        let actual = regex_from_glob("log.txt");
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must dispatch to an Action matched by a glob pattern.
+    ///
+    /// The parse method must fall back to matching the first token against
+    /// registered glob patterns when no literal Action keyword matches.
+    #[test]
+    fn cherry_parse_glob() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("git:*").unwrap())
+            .unwrap();
+
+        let expected = Request::new(cherry.actions.get("git:*").unwrap());
+        let actual = cherry.parse(["git:log"].into_iter()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must prefer an exact literal match over any glob.
+    ///
+    /// The parse method must select the exact literal Action keyword over a
+    /// glob pattern that would also match the same keyword.
+    #[test]
+    fn cherry_parse_glob_prefers_literal() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("git:*").unwrap())
+            .unwrap()
+            .insert(Action::new("git:log").unwrap())
+            .unwrap();
+
+        let expected = Request::new(cherry.actions.get("git:log").unwrap());
+        let actual = cherry.parse(["git:log"].into_iter()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::parse must prefer the glob with the fewest wildcards.
+    ///
+    /// The parse method must, among multiple matching glob patterns, select
+    /// the one with the fewest wildcard characters for determinism.
+    #[test]
+    fn cherry_parse_glob_prefers_fewest_wildcards() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("git:??g").unwrap())
+            .unwrap()
+            .insert(Action::new("git:l?g").unwrap())
+            .unwrap();
+
+        let expected = Request::new(cherry.actions.get("git:l?g").unwrap());
+        let actual = cherry.parse(["git:log"].into_iter()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::dispatch must run the matched Action's callback.
+    ///
+    /// The dispatch method must parse the command and run the matched
+    /// Action's callback, returning its result.
+    #[test]
+    fn cherry_dispatch() {
+        let cherry = Cherry::new()
+            .insert(Action::new("my_action").unwrap().then(|_request| 42))
+            .unwrap();
+
+        let actual = cherry.dispatch(["my_action"].into_iter()).unwrap();
+
+        assert_eq!(42, actual);
+    }
+
+    /// Cherry::dispatch must run prerequisites before the matched Action.
+    ///
+    /// The dispatch method must run a declared prerequisite's callback
+    /// before running the matched Action's own callback.
+    #[test]
+    fn cherry_dispatch_prerequisite() {
+        let order = Rc::new(RefCell::new(Vec::new()));
+
+        let build_order = Rc::clone(&order);
+        let deploy_order = Rc::clone(&order);
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("build")
+                    .unwrap()
+                    .then(move |_request| build_order.borrow_mut().push("build")),
+            )
+            .unwrap()
+            .insert(
+                Action::new("deploy")
+                    .unwrap()
+                    .requires("build")
+                    .then(move |_request| deploy_order.borrow_mut().push("deploy")),
+            )
+            .unwrap();
+
+        cherry.dispatch(["deploy"].into_iter()).unwrap();
+
+        assert_eq!(vec!["build", "deploy"], *order.borrow());
+    }
+
+    /// Cherry::dispatch must error on an unregistered prerequisite.
+    ///
+    /// The dispatch method must error when a declared prerequisite does not
+    /// match any registered Action.
+    #[test]
+    fn cherry_dispatch_missing_prerequisite() {
+        let expected = Error::new("Prerequisite 'build' is not a registered Action.");
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("deploy")
+                    .unwrap()
+                    .requires("build")
+                    .then(|_request| ()),
+            )
+            .unwrap();
+
+        let actual = cherry.dispatch(["deploy"].into_iter()).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::dispatch must error on a prerequisite cycle.
+    ///
+    /// The dispatch method must error when a prerequisite chain cycles back
+    /// on itself, rather than recursing forever.
+    #[test]
+    fn cherry_dispatch_cycle() {
+        let expected = Error::new("Prerequisite cycle detected at 'a'.");
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("a")
+                    .unwrap()
+                    .requires("b")
+                    .then(|_request| ()),
+            )
+            .unwrap()
+            .insert(
+                Action::new("b")
+                    .unwrap()
+                    .requires("a")
+                    .then(|_request| ()),
+            )
+            .unwrap();
+
+        let actual = cherry.dispatch(["a"].into_iter()).unwrap_err();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// Cherry::generate_completions must build a bash completion function.
+    ///
+    /// The generate_completions method must emit a `complete -F` function
+    /// named after the binary, offering root Action keywords at depth one.
+    #[test]
+    fn cherry_generate_completions_bash_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Bash, "my_app");
+
+        assert!(actual.contains("_my_app_completions() {"));
+        assert!(actual.contains("complete -F _my_app_completions my_app"));
+        assert!(actual.contains("opts=\"my_action\""));
+    }
+
+    /// Cherry::generate_completions must list Fields and Flags one level deep.
+    ///
+    /// The generate_completions method must offer an Action's Field and Flag
+    /// long/short names as completions for the word following its keyword.
+    #[test]
+    fn cherry_generate_completions_bash_fields_and_flags() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("output").unwrap().short('o'))
+                    .unwrap()
+                    .insert_flag(Flag::new("verbose").unwrap().short('v'))
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Bash, "my_app");
+
+        assert!(actual.contains("my_action)"));
+        assert!(actual.contains("--output"));
+        assert!(actual.contains("-o"));
+        assert!(actual.contains("--verbose"));
+        assert!(actual.contains("-v"));
+    }
+
+    /// Cherry::generate_completions must recurse into child Actions.
+    ///
+    /// The generate_completions method must nest a further case on
+    /// COMP_WORDS when an Action has child Actions, offering the child
+    /// keyword one level deeper.
+    #[test]
+    fn cherry_generate_completions_bash_children() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_child(Action::new("my_child").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Bash, "my_app");
+
+        assert!(actual.contains("COMP_WORDS[2]"));
+        assert!(actual.contains("my_child"));
+    }
+
+    /// Cherry::generate_completions must build a zsh completion function.
+    ///
+    /// The generate_completions method must emit a `_{bin_name}` function
+    /// registered via `compdef`, listing root Action keywords alongside
+    /// their descriptions for zsh's `_describe`.
+    #[test]
+    fn cherry_generate_completions_zsh_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap().description("Run my action."))
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Zsh, "my_app");
+
+        assert!(actual.contains("_my_app() {"));
+        assert!(actual.contains("compdef _my_app my_app"));
+        assert!(actual.contains("'my_action:Run my action.'"));
+    }
+
+    /// Cherry::generate_completions must describe zsh Field/Flag specs.
+    ///
+    /// The generate_completions method must emit an `_arguments` spec per
+    /// Field and Flag, carrying its description as inline help text.
+    #[test]
+    fn cherry_generate_completions_zsh_fields_and_flags() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("output").unwrap().short('o').description("Output path."))
+                    .unwrap()
+                    .insert_flag(Flag::new("verbose").unwrap().short('v'))
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Zsh, "my_app");
+
+        assert!(actual.contains("_my_app_my_action() {"));
+        assert!(actual.contains("[Output path.]"));
+        assert!(actual.contains("-o,--output"));
+        assert!(actual.contains("--verbose"));
+    }
+
+    /// Cherry::generate_completions must offer zsh Field possible_values.
+    ///
+    /// The generate_completions method must render a Field's possible_values
+    /// as a zsh `(a b c)` choice list in place of a bare `:value:`.
+    #[test]
+    fn cherry_generate_completions_zsh_possible_values() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("mode").unwrap().possible_values(&["add", "remove"]))
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Zsh, "my_app");
+
+        assert!(actual.contains(":value:(add remove)"));
+    }
+
+    /// Cherry::generate_completions must build a fish completion script.
+    ///
+    /// The generate_completions method must emit one `complete -c` line per
+    /// root Action, conditioned on `__fish_use_subcommand`.
+    #[test]
+    fn cherry_generate_completions_fish_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap().description("Run my action."))
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Fish, "my_app");
+
+        assert!(actual.contains("complete -c my_app -n '__fish_use_subcommand' -a 'my_action' -d 'Run my action.'"));
+    }
+
+    /// Cherry::generate_completions must condition fish Field/Flag lines.
+    ///
+    /// The generate_completions method must only offer a nested Action's
+    /// Fields and Flags once its ancestor keywords have already been typed.
+    #[test]
+    fn cherry_generate_completions_fish_fields_and_flags() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("output").unwrap().short('o'))
+                    .unwrap()
+                    .insert_flag(Flag::new("verbose").unwrap().short('v'))
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Fish, "my_app");
+
+        assert!(actual.contains("__fish_seen_subcommand_from my_action"));
+        assert!(actual.contains("-l output"));
+        assert!(actual.contains("-s o"));
+        assert!(actual.contains("-l verbose"));
+        assert!(actual.contains("-s v"));
+    }
+
+    /// Cherry::generate_completions must offer fish Field possible_values.
+    ///
+    /// The generate_completions method must render a Field's possible_values
+    /// as a fish `-a '...'` suggestion list.
+    #[test]
+    fn cherry_generate_completions_fish_possible_values() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_field(Field::new("mode").unwrap().possible_values(&["add", "remove"]))
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::Fish, "my_app");
+
+        assert!(actual.contains("-l mode -d '' -r -a 'add remove'"));
+    }
+
+    /// Cherry::generate_completions must build a PowerShell completer.
+    ///
+    /// The generate_completions method must emit a
+    /// `Register-ArgumentCompleter -Native` block offering root Action
+    /// keywords at depth one.
+    #[test]
+    fn cherry_generate_completions_powershell_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::PowerShell, "my_app");
+
+        assert!(actual.contains("Register-ArgumentCompleter -Native -CommandName my_app"));
+        assert!(actual.contains("@('my_action')"));
+    }
+
+    /// Cherry::generate_completions must recurse PowerShell into children.
+    ///
+    /// The generate_completions method must nest a further `switch` on the
+    /// typed token chain when an Action has child Actions.
+    #[test]
+    fn cherry_generate_completions_powershell_children() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_child(Action::new("my_child").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let actual = cherry.generate_completions(Shell::PowerShell, "my_app");
+
+        assert!(actual.contains("switch ($tokens[1])"));
+        assert!(actual.contains("'my_child'"));
+    }
+
+    /// Cherry::repl_with must run a successfully parsed Action's callback.
+    ///
+    /// The repl_with method must parse each line read and, on success, run
+    /// the located Action's then callback before reading the next line.
+    #[test]
+    fn cherry_repl_with_runs_action() {
+        let ran = Rc::new(Cell::new(false));
+        let flag = Rc::clone(&ran);
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .then(move |_request| flag.set(true)),
+            )
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with(Cursor::new("my_action\nexit\n"), &mut output, None);
+
+        assert!(ran.get());
+    }
+
+    /// Cherry::repl_with must print the error on a parse error and keep looping.
+    ///
+    /// The repl_with method must not abort the loop when a line fails to
+    /// parse, instead writing the Error's Display to the writer.
+    #[test]
+    fn cherry_repl_with_prints_error_on_parse_error() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with(Cursor::new("not_an_action\nexit\n"), &mut output, None);
+
+        let written = String::from_utf8(output).unwrap();
+        let expected = Error::unknown_action("not_an_action", ["my_action"].into_iter());
+        assert_eq!(written.trim_end(), expected.to_string());
+    }
+
+    /// Cherry::repl_with must stop reading on the exit and quit sentinels.
+    ///
+    /// The repl_with method must end the loop as soon as a line equal to
+    /// `exit` or `quit` is read, without attempting to parse it.
+    #[test]
+    fn cherry_repl_with_exit_sentinel() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with(Cursor::new("quit\nmy_action\n"), &mut output, None);
+
+        assert!(output.is_empty());
+    }
+
+    /// Cherry::repl_with must write the prompt before each line is read.
+    ///
+    /// The repl_with method must write the provided prompt to the writer
+    /// immediately before attempting to read each line.
+    #[test]
+    fn cherry_repl_with_writes_prompt() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with(Cursor::new("exit\n"), &mut output, Some("> "));
+
+        assert!(String::from_utf8(output).unwrap().starts_with("> "));
+    }
+
+    /// Cherry::repl_with_handler must call the handler with a run's result.
+    ///
+    /// The repl_with_handler method must invoke the supplied handler with
+    /// the value returned by a successfully run Action, rather than
+    /// discarding it.
+    #[test]
+    fn cherry_repl_with_handler_calls_handler() {
+        let cherry = Cherry::new()
+            .insert(Action::new("greet").unwrap().then(|_request| String::from("Hello!")))
+            .unwrap();
+
+        let seen = Rc::new(RefCell::new(None));
+        let captured = Rc::clone(&seen);
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new("greet\nexit\n"),
+            &mut output,
+            &ReplOptions::new(),
+            move |message| *captured.borrow_mut() = Some(message),
+        );
+
+        assert_eq!(Some(String::from("Hello!")), *seen.borrow());
+    }
+
+    /// Cherry::repl_with_handler must recognise the `:quit` meta-command.
+    ///
+    /// The repl_with_handler method must end the loop as soon as `:quit` is
+    /// read, the same as the bare `exit`/`quit` sentinels.
+    #[test]
+    fn cherry_repl_with_handler_quit_meta_command() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new(":quit\nmy_action\n"),
+            &mut output,
+            &ReplOptions::new(),
+            |_| (),
+        );
+
+        assert!(output.is_empty());
+    }
+
+    /// Cherry::repl_with_handler must recognise the `:help` meta-command.
+    ///
+    /// The repl_with_handler method must print the top-level help block and
+    /// continue the loop when `:help` is read, without attempting to parse
+    /// it as an Action.
+    #[test]
+    fn cherry_repl_with_handler_help_meta_command() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new(":help\nexit\n"),
+            &mut output,
+            &ReplOptions::new(),
+            |_| (),
+        );
+
+        let written = String::from_utf8(output).unwrap();
+        assert_eq!(format!("{}\n", cherry.help(&[])), written);
+    }
+
+    /// Cherry::repl_with_handler must persist lines to the history file.
+    ///
+    /// The repl_with_handler method must append each successfully read,
+    /// non-meta line to the ReplOptions history file as it is entered.
+    #[test]
+    fn cherry_repl_with_handler_persists_history() {
+        let cherry = Cherry::new()
+            .insert(Action::new("my_action").unwrap().then(|_request| ()))
+            .unwrap();
+
+        let path = std::env::temp_dir().join("cherry_repl_with_handler_persists_history_test");
+        let _ = std::fs::remove_file(&path);
+        let options = ReplOptions::new().history(&path);
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(Cursor::new("my_action\nexit\n"), &mut output, &options, |_| ());
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!("my_action\n", written);
+    }
+
+    /// Cherry::repl_with_handler must join a backslash-continued line.
+    ///
+    /// The repl_with_handler method must read another line when the first
+    /// ends with a lone, unescaped backslash, joining the two with a single
+    /// space and dropping the backslash marker, before parsing the result.
+    #[test]
+    fn cherry_repl_with_handler_backslash_continuation() {
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_flag(Flag::new("my_flag").unwrap())
+                    .unwrap()
+                    .then(|_request| ()),
+            )
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new("my_action \\\n--my_flag\nexit\n"),
+            &mut output,
+            &ReplOptions::new(),
+            |_| (),
+        );
+
+        assert!(output.is_empty());
+    }
+
+    /// Cherry::repl_with_handler must join a line with an unterminated quote.
+    ///
+    /// The repl_with_handler method must read another line when the first
+    /// has an unterminated quote, joining the two with a newline so the
+    /// quoted Action keyword is parsed as a single token.
+    #[test]
+    fn cherry_repl_with_handler_quote_continuation() {
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("my\naction")
+                    .unwrap()
+                    .then(|_request| ()),
+            )
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new("'my\naction'\nexit\n"),
+            &mut output,
+            &ReplOptions::new(),
+            |_| (),
+        );
+
+        assert!(output.is_empty());
+    }
+
+    /// Cherry::repl_with_handler must write the continuation prompt.
+    ///
+    /// The repl_with_handler method must write options's continuation
+    /// prompt, rather than its regular prompt, before reading a
+    /// continuation line.
+    #[test]
+    fn cherry_repl_with_handler_writes_continuation_prompt() {
+        let cherry = Cherry::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_flag(Flag::new("my_flag").unwrap())
+                    .unwrap()
+                    .then(|_request| ()),
+            )
+            .unwrap();
+
+        let options = ReplOptions::new().prompt("> ").continuation_prompt("... ");
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new("my_action \\\n--my_flag\nexit\n"),
+            &mut output,
+            &options,
+            |_| (),
+        );
+
+        assert_eq!("> ... > ", String::from_utf8(output).unwrap());
+    }
+
+    /// Cherry::repl_with_handler must recognise the `:history` meta-command.
+    ///
+    /// The repl_with_handler method must print every line retained in the
+    /// in-memory history ring, most-recent last, when `:history` is read.
+    #[test]
+    fn cherry_repl_with_handler_history_meta_command() {
+        let cherry = Cherry::new()
+            .insert(Action::new("my_action").unwrap().then(|_request| ()))
+            .unwrap();
+
+        let mut output = Vec::new();
+        cherry.repl_with_handler(
+            Cursor::new("my_action\n:history\nexit\n"),
+            &mut output,
+            &ReplOptions::new(),
+            |_| (),
+        );
+
+        assert_eq!("my_action\n", String::from_utf8(output).unwrap());
+    }
+
+    /// Cherry::repl_complete must suggest root Action keywords.
+    ///
+    /// The repl_complete method must return every registered root Action
+    /// keyword starting with the partial token when no Action has yet been
+    /// selected.
+    #[test]
+    fn cherry_repl_complete_root() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("greet").unwrap())
+            .unwrap()
+            .insert(Action::new("goodbye").unwrap())
+            .unwrap();
+
+        assert_eq!(vec![String::from("greet")], cherry.repl_complete("gre"));
+    }
+
+    /// Cherry::repl_complete must suggest a selected Action's Fields and Flags.
+    ///
+    /// The repl_complete method must walk into the selected Action and
+    /// suggest its completion words once its keyword has already been
+    /// typed.
+    #[test]
+    fn cherry_repl_complete_fields_and_flags() {
+        let cherry = Cherry::<()>::new()
+            .insert(
+                Action::new("my_action")
+                    .unwrap()
+                    .insert_flag(Flag::new("verbose").unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![String::from("--verbose")],
+            cherry.repl_complete("my_action --verb")
+        );
+    }
+
+    /// Cherry::repl_complete must return nothing past an unknown Action.
+    ///
+    /// The repl_complete method must return an empty Vec if the first token
+    /// does not match any registered Action keyword.
+    #[test]
+    fn cherry_repl_complete_unknown_action() {
+        let cherry = Cherry::<()>::new()
+            .insert(Action::new("my_action").unwrap())
+            .unwrap();
+
+        assert!(cherry.repl_complete("not_an_action --verb").is_empty());
+    }
 }
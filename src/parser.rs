@@ -0,0 +1,337 @@
+//! Parser module
+//!
+//! The Parser module provides some default value parser constructors to be
+//! used with `Argument::value_parser` and `Field::value_parser`. Unlike the
+//! bool-returning helpers in the `validate` module, these construct a
+//! closure that both validates and converts the raw value into a typed
+//! value.
+
+use std::path::PathBuf;
+
+/// Construct a parser that accepts any integer.
+///
+/// Construct a parser converting the raw value into an `i64`, with no
+/// range restriction. Equivalent to `integer_range(i64::MIN, i64::MAX)`.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::integer;
+///
+/// let parser = integer();
+///
+/// assert_eq!(Ok(-5), parser("-5"));
+/// assert!(parser("abc").is_err());
+/// ```
+pub fn integer() -> impl Fn(&str) -> Result<i64, String> {
+    integer_range(i64::MIN, i64::MAX)
+}
+
+/// Construct a parser that accepts an integer within a range.
+///
+/// Construct a parser accepting integers in the inclusive range
+/// `min..=max`, converting the raw value into an `i64`.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::integer_range;
+///
+/// let parser = integer_range(0, 65535);
+///
+/// assert_eq!(Ok(8080), parser("8080"));
+/// assert!(parser("-1").is_err());
+/// assert!(parser("100000").is_err());
+/// assert!(parser("abc").is_err());
+/// ```
+pub fn integer_range(min: i64, max: i64) -> impl Fn(&str) -> Result<i64, String> {
+    move |value: &str| {
+        let parsed = value
+            .parse::<i64>()
+            .map_err(|_| format!("'{value}' is not an integer"))?;
+
+        if parsed < min || parsed > max {
+            return Err(format!("'{value}' is not in range {min}-{max}"));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Construct a parser that accepts any float.
+///
+/// Construct a parser converting the raw value into an `f64`, with no
+/// range restriction. Equivalent to `bounded_float(f64::MIN, f64::MAX)`.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::float;
+///
+/// let parser = float();
+///
+/// assert_eq!(Ok(-0.5), parser("-0.5"));
+/// assert!(parser("abc").is_err());
+/// ```
+pub fn float() -> impl Fn(&str) -> Result<f64, String> {
+    bounded_float(f64::MIN, f64::MAX)
+}
+
+/// Construct a parser that accepts a float within a range.
+///
+/// Construct a parser accepting floats in the inclusive range
+/// `min..=max`, converting the raw value into an `f64`.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::bounded_float;
+///
+/// let parser = bounded_float(0.0, 1.0);
+///
+/// assert_eq!(Ok(0.5), parser("0.5"));
+/// assert!(parser("1.5").is_err());
+/// assert!(parser("abc").is_err());
+/// ```
+pub fn bounded_float(min: f64, max: f64) -> impl Fn(&str) -> Result<f64, String> {
+    move |value: &str| {
+        let parsed = value
+            .parse::<f64>()
+            .map_err(|_| format!("'{value}' is not a number"))?;
+
+        if parsed < min || parsed > max {
+            return Err(format!("'{value}' is not in range {min}-{max}"));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Construct a parser that accepts a boolean.
+///
+/// Construct a parser accepting the literal tokens `"true"` and `"false"`,
+/// converting the raw value into a `bool`. Mirrors the strictness of
+/// `FieldType::Bool`: no other casing or alias is accepted.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::boolean;
+///
+/// let parser = boolean();
+///
+/// assert_eq!(Ok(true), parser("true"));
+/// assert!(parser("TRUE").is_err());
+/// assert!(parser("yes").is_err());
+/// ```
+pub fn boolean() -> impl Fn(&str) -> Result<bool, String> {
+    move |value: &str| {
+        value
+            .parse::<bool>()
+            .map_err(|_| format!("'{value}' is not a boolean"))
+    }
+}
+
+/// Construct a parser that accepts an existing path.
+///
+/// Construct a parser accepting any value that resolves to a path already
+/// present on the filesystem, converting the raw value into a `PathBuf`.
+///
+/// # Example
+/// ```rust
+/// use cherry::parser::existing_path;
+///
+/// let parser = existing_path();
+///
+/// assert!(parser("/").is_ok());
+/// assert!(parser("/does/not/exist").is_err());
+/// ```
+pub fn existing_path() -> impl Fn(&str) -> Result<PathBuf, String> {
+    move |value: &str| {
+        let path = PathBuf::from(value);
+
+        if !path.exists() {
+            return Err(format!("'{value}' does not exist"));
+        }
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Method integer must accept any integer.
+    ///
+    /// If provided a negative integer, integer must return Ok with the
+    /// parsed value.
+    #[test]
+    fn integer_accepts_any_value() {
+        let parser = integer();
+
+        assert_eq!(Ok(-5), parser("-5"));
+    }
+
+    /// Method integer must reject a non integer value.
+    ///
+    /// If provided a non integer value, integer must return Err.
+    #[test]
+    fn integer_non_integer() {
+        let parser = integer();
+
+        assert!(parser("abc").is_err());
+    }
+
+    /// Method integer_range must accept a value within range.
+    ///
+    /// If provided a value within the range, integer_range must return Ok
+    /// with the parsed integer.
+    #[test]
+    fn integer_range_within() {
+        let parser = integer_range(0, 100);
+
+        assert_eq!(Ok(50), parser("50"));
+    }
+
+    /// Method integer_range must reject a value below the range.
+    ///
+    /// If provided a value below the minimum, integer_range must return Err.
+    #[test]
+    fn integer_range_below() {
+        let parser = integer_range(0, 100);
+
+        assert!(parser("-1").is_err());
+    }
+
+    /// Method integer_range must reject a value above the range.
+    ///
+    /// If provided a value above the maximum, integer_range must return Err.
+    #[test]
+    fn integer_range_above() {
+        let parser = integer_range(0, 100);
+
+        assert!(parser("101").is_err());
+    }
+
+    /// Method integer_range must reject a non integer value.
+    ///
+    /// If provided a non integer value, integer_range must return Err.
+    #[test]
+    fn integer_range_non_integer() {
+        let parser = integer_range(0, 100);
+
+        assert!(parser("abc").is_err());
+    }
+
+    /// Method float must accept any float.
+    ///
+    /// If provided a negative float, float must return Ok with the parsed
+    /// value.
+    #[test]
+    fn float_accepts_any_value() {
+        let parser = float();
+
+        assert_eq!(Ok(-0.5), parser("-0.5"));
+    }
+
+    /// Method float must reject a non numeric value.
+    ///
+    /// If provided a non numeric value, float must return Err.
+    #[test]
+    fn float_non_numeric() {
+        let parser = float();
+
+        assert!(parser("abc").is_err());
+    }
+
+    /// Method bounded_float must accept a value within range.
+    ///
+    /// If provided a value within the range, bounded_float must return Ok
+    /// with the parsed float.
+    #[test]
+    fn bounded_float_within() {
+        let parser = bounded_float(0.0, 1.0);
+
+        assert_eq!(Ok(0.5), parser("0.5"));
+    }
+
+    /// Method bounded_float must reject a value below the range.
+    ///
+    /// If provided a value below the minimum, bounded_float must return Err.
+    #[test]
+    fn bounded_float_below() {
+        let parser = bounded_float(0.0, 1.0);
+
+        assert!(parser("-0.5").is_err());
+    }
+
+    /// Method bounded_float must reject a value above the range.
+    ///
+    /// If provided a value above the maximum, bounded_float must return Err.
+    #[test]
+    fn bounded_float_above() {
+        let parser = bounded_float(0.0, 1.0);
+
+        assert!(parser("1.5").is_err());
+    }
+
+    /// Method bounded_float must reject a non numeric value.
+    ///
+    /// If provided a non numeric value, bounded_float must return Err.
+    #[test]
+    fn bounded_float_non_numeric() {
+        let parser = bounded_float(0.0, 1.0);
+
+        assert!(parser("abc").is_err());
+    }
+
+    /// Method boolean must accept the literal "true".
+    ///
+    /// If provided "true", boolean must return Ok(true).
+    #[test]
+    fn boolean_true() {
+        let parser = boolean();
+
+        assert_eq!(Ok(true), parser("true"));
+    }
+
+    /// Method boolean must accept the literal "false".
+    ///
+    /// If provided "false", boolean must return Ok(false).
+    #[test]
+    fn boolean_false() {
+        let parser = boolean();
+
+        assert_eq!(Ok(false), parser("false"));
+    }
+
+    /// Method boolean must reject any other casing or alias.
+    ///
+    /// If provided a value other than the literal "true" or "false", boolean
+    /// must return Err.
+    #[test]
+    fn boolean_invalid() {
+        let parser = boolean();
+
+        assert!(parser("TRUE").is_err());
+        assert!(parser("yes").is_err());
+    }
+
+    /// Method existing_path must accept an existing path.
+    ///
+    /// If provided a path that exists, existing_path must return Ok with the
+    /// parsed PathBuf.
+    #[test]
+    fn existing_path_exists() {
+        let parser = existing_path();
+
+        assert_eq!(Ok(PathBuf::from("/")), parser("/"));
+    }
+
+    /// Method existing_path must reject a missing path.
+    ///
+    /// If provided a path that does not exist, existing_path must return Err.
+    #[test]
+    fn existing_path_missing() {
+        let parser = existing_path();
+
+        assert!(parser("/does/not/exist/cherry").is_err());
+    }
+}
@@ -0,0 +1,366 @@
+//! Repl module
+//!
+//! Houses the configuration type for `Cherry::repl_with_handler`, along with
+//! the small file-backed persistence helpers it uses to load and append to a
+//! history dotfile, the in-memory history ring behind the `:history`
+//! meta-command, and the multi-line continuation detection that lets a line
+//! with an unterminated quote or a trailing backslash span more than one
+//! read.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+/// The maximum number of entries retained in the in-memory history ring
+/// consulted by the `:history` meta-command.
+pub(crate) const HISTORY_RING_CAPACITY: usize = 50;
+
+/// Options configuring an interactive `Cherry::repl_with_handler` session.
+///
+/// Built up the same way as the rest of the crate's configuration types:
+/// `ReplOptions::new()` followed by chained setters, each consuming and
+/// returning `self`.
+///
+/// # Example
+/// ```rust
+/// use cherry::ReplOptions;
+/// use std::path::Path;
+///
+/// let options = ReplOptions::new()
+///     .prompt("> ")
+///     .continuation_prompt("... ")
+///     .history(Path::new(".my_app_history"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReplOptions {
+    /// The prompt written before each line is read.
+    prompt: Option<String>,
+
+    /// The prompt written before each continuation line is read, while the
+    /// buffered command is still incomplete.
+    continuation_prompt: Option<String>,
+
+    /// The dotfile path persistent line history is loaded from and appended
+    /// to.
+    history: Option<PathBuf>,
+}
+
+impl ReplOptions {
+    /// Create new, empty ReplOptions.
+    ///
+    /// Create a new ReplOptions instance with no prompt and no history file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::ReplOptions;
+    ///
+    /// let options = ReplOptions::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the prompt written before each line is read.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::ReplOptions;
+    ///
+    /// let options = ReplOptions::new().prompt("> ");
+    /// ```
+    pub fn prompt(mut self, prompt: &str) -> Self {
+        self.prompt = Some(String::from(prompt));
+        self
+    }
+
+    /// Set the prompt written before each continuation line is read.
+    ///
+    /// Consulted while the buffered command is incomplete, i.e. it ends with
+    /// an unterminated quote or a trailing, unescaped backslash. Defaults to
+    /// the regular `prompt` when unset.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::ReplOptions;
+    ///
+    /// let options = ReplOptions::new().prompt("> ").continuation_prompt("... ");
+    /// ```
+    pub fn continuation_prompt(mut self, prompt: &str) -> Self {
+        self.continuation_prompt = Some(String::from(prompt));
+        self
+    }
+
+    /// Set the dotfile path persistent line history is loaded from and
+    /// appended to.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::ReplOptions;
+    /// use std::path::Path;
+    ///
+    /// let options = ReplOptions::new().history(Path::new(".my_app_history"));
+    /// ```
+    pub fn history(mut self, path: &Path) -> Self {
+        self.history = Some(path.to_path_buf());
+        self
+    }
+
+    /// Get the configured prompt, if any.
+    pub(crate) fn prompt_str(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    /// Get the configured continuation prompt, falling back to the regular
+    /// prompt when unset.
+    pub(crate) fn continuation_prompt_str(&self) -> Option<&str> {
+        self.continuation_prompt.as_deref().or(self.prompt.as_deref())
+    }
+
+    /// Get the configured history file path, if any.
+    pub(crate) fn history_path(&self) -> Option<&Path> {
+        self.history.as_deref()
+    }
+}
+
+/// Load persisted REPL history lines from `path`.
+///
+/// Returns an empty Vec if `path` does not exist or cannot be read, so a
+/// fresh history file is treated the same as an empty one.
+pub(crate) fn load_history(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .map(|contents| contents.lines().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Append a single REPL history line to the dotfile at `path`.
+///
+/// Creates `path` if it does not already exist. Silently does nothing if the
+/// file cannot be opened for appending.
+pub(crate) fn append_history(path: &Path, line: &str) {
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{line}");
+    }
+}
+
+/// Push `line` onto an in-memory history ring, evicting the oldest entry
+/// once `HISTORY_RING_CAPACITY` is reached.
+pub(crate) fn push_ring(ring: &mut VecDeque<String>, line: &str) {
+    if ring.len() == HISTORY_RING_CAPACITY {
+        ring.pop_front();
+    }
+    ring.push_back(String::from(line));
+}
+
+/// Determine whether a buffered REPL command is incomplete, and if so, how
+/// the next line read should be joined onto it.
+///
+/// Mirrors the quote and escape handling `Cherry::parse_str` applies: a
+/// command with an unterminated `'` or `"` is incomplete, and the next line
+/// is joined with a newline so the quoted content is preserved verbatim. A
+/// command ending in a lone, unescaped backslash is also incomplete; the
+/// marker is dropped and the next line is joined with a single space
+/// instead, the same continuation convention a shell uses.
+pub(crate) fn continuation(command: &str) -> Option<&'static str> {
+    let mut quote = None;
+    let mut chars = command.chars();
+    let mut trailing_escape = false;
+    while let Some(character) = chars.next() {
+        trailing_escape = false;
+        match character {
+            '\\' => trailing_escape = chars.next().is_none(),
+            '"' if quote == Some('"') => quote = None,
+            '"' if quote.is_none() => quote = Some('"'),
+            '\'' if quote == Some('\'') => quote = None,
+            '\'' if quote.is_none() => quote = Some('\''),
+            _ => {}
+        }
+    }
+
+    if trailing_escape {
+        Some(" ")
+    } else if quote.is_some() {
+        Some("\n")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// ReplOptions::new must create as per struct initialisation.
+    ///
+    /// The new method on ReplOptions must create an object as per the struct
+    /// initialiser syntax.
+    #[test]
+    fn repl_options_new() {
+        let expected = ReplOptions {
+            prompt: None,
+            continuation_prompt: None,
+            history: None,
+        };
+        let actual = ReplOptions::new();
+
+        assert_eq!(expected, actual);
+    }
+
+    /// ReplOptions::prompt must set the prompt.
+    ///
+    /// The prompt method must set the prompt retrievable via prompt_str.
+    #[test]
+    fn repl_options_prompt() {
+        let actual = ReplOptions::new().prompt("> ");
+
+        assert_eq!(Some("> "), actual.prompt_str());
+    }
+
+    /// ReplOptions::continuation_prompt must set the continuation prompt.
+    ///
+    /// The continuation_prompt method must set the prompt retrievable via
+    /// continuation_prompt_str.
+    #[test]
+    fn repl_options_continuation_prompt() {
+        let actual = ReplOptions::new().continuation_prompt("... ");
+
+        assert_eq!(Some("... "), actual.continuation_prompt_str());
+    }
+
+    /// ReplOptions::continuation_prompt_str must fall back to the regular
+    /// prompt when unset.
+    ///
+    /// The continuation_prompt_str method must return the regular prompt if
+    /// no continuation prompt was set.
+    #[test]
+    fn repl_options_continuation_prompt_str_falls_back() {
+        let actual = ReplOptions::new().prompt("> ");
+
+        assert_eq!(Some("> "), actual.continuation_prompt_str());
+    }
+
+    /// ReplOptions::history must set the history path.
+    ///
+    /// The history method must set the history path retrievable via
+    /// history_path.
+    #[test]
+    fn repl_options_history() {
+        let actual = ReplOptions::new().history(Path::new(".history"));
+
+        assert_eq!(Some(Path::new(".history")), actual.history_path());
+    }
+
+    /// load_history must return an empty Vec for a missing file.
+    ///
+    /// The load_history function must return an empty Vec if the history
+    /// file does not exist.
+    #[test]
+    fn load_history_missing() {
+        let actual = load_history(Path::new("/does/not/exist/cherry_history"));
+
+        assert!(actual.is_empty());
+    }
+
+    /// append_history then load_history must round-trip a line.
+    ///
+    /// A line appended via append_history must be returned by a subsequent
+    /// load_history call against the same path.
+    #[test]
+    fn history_round_trip() {
+        let path = std::env::temp_dir().join("cherry_repl_history_round_trip_test");
+        let _ = fs::remove_file(&path);
+
+        append_history(&path, "my_action --flag");
+        append_history(&path, "other_action");
+
+        let actual = load_history(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            vec![
+                String::from("my_action --flag"),
+                String::from("other_action")
+            ],
+            actual
+        );
+    }
+
+    /// continuation must return None for a complete command.
+    ///
+    /// The continuation function must return None when the command has no
+    /// unterminated quote and no trailing, unescaped backslash.
+    #[test]
+    fn continuation_complete() {
+        assert_eq!(None, continuation("my_action --flag value"));
+    }
+
+    /// continuation must detect an unterminated double quote.
+    ///
+    /// The continuation function must return the newline joiner when the
+    /// command has an opening double quote with no matching close.
+    #[test]
+    fn continuation_unterminated_double_quote() {
+        assert_eq!(Some("\n"), continuation("my_action \"some value"));
+    }
+
+    /// continuation must detect an unterminated single quote.
+    ///
+    /// The continuation function must return the newline joiner when the
+    /// command has an opening single quote with no matching close.
+    #[test]
+    fn continuation_unterminated_single_quote() {
+        assert_eq!(Some("\n"), continuation("my_action 'some value"));
+    }
+
+    /// continuation must detect a trailing continuation backslash.
+    ///
+    /// The continuation function must return the space joiner when the
+    /// command ends with a lone, unescaped backslash.
+    #[test]
+    fn continuation_trailing_backslash() {
+        assert_eq!(Some(" "), continuation("my_action --flag \\"));
+    }
+
+    /// continuation must not treat an escaped character as a continuation.
+    ///
+    /// The continuation function must return None when the trailing
+    /// backslash escapes a following character rather than standing alone.
+    #[test]
+    fn continuation_escaped_character_not_trailing() {
+        assert_eq!(None, continuation("my_action \\\""));
+    }
+
+    /// push_ring must retain entries up to its capacity.
+    ///
+    /// The push_ring function must keep every pushed entry while the ring
+    /// has not yet reached HISTORY_RING_CAPACITY.
+    #[test]
+    fn push_ring_retains_entries() {
+        let mut ring = VecDeque::new();
+        push_ring(&mut ring, "one");
+        push_ring(&mut ring, "two");
+
+        assert_eq!(
+            VecDeque::from([String::from("one"), String::from("two")]),
+            ring
+        );
+    }
+
+    /// push_ring must evict the oldest entry once full.
+    ///
+    /// The push_ring function must drop the front of the ring when pushing
+    /// past HISTORY_RING_CAPACITY, keeping only the most recent entries.
+    #[test]
+    fn push_ring_evicts_oldest() {
+        let mut ring = VecDeque::new();
+        for index in 0..HISTORY_RING_CAPACITY {
+            push_ring(&mut ring, &format!("command {index}"));
+        }
+        push_ring(&mut ring, "latest");
+
+        assert_eq!(HISTORY_RING_CAPACITY, ring.len());
+        assert_eq!(Some(&String::from("command 1")), ring.front());
+        assert_eq!(Some(&String::from("latest")), ring.back());
+    }
+}
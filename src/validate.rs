@@ -3,7 +3,12 @@
 //! The Validate module provides some default validation methods to be used
 //! with the filters on Arguments and Fields. Note that all methods perform
 //! validation against arabic numerals and english alphabet.
+//!
+//! It also provides the `Validator` trait, which lets the predicates in this
+//! module (or any `Fn(&str) -> bool`) be combined with `and`/`or`/`not` into
+//! a single rule that reports which part of the rule failed.
 
+use crate::error::{self, Error};
 /// Determine if the valud is alphanumeric.
 ///
 /// Determine whether the provided value contains only alphanumeric characters.
@@ -107,6 +112,325 @@ pub fn is_positive(value: &str) -> bool {
     value.parse::<f32>().map_or(false, |value| value > 0.0)
 }
 
+/// Determine if the value is a negative number token.
+///
+/// Determine whether the provided value is a hyphen immediately followed by
+/// a valid numeric literal, e.g. `-1`, `-1.5` or `-.10`. Unlike `is_negative`,
+/// this doesn't evaluate the token's sign, so it also accepts `-0` and
+/// `-0.`; it's meant for telling a negative numeric Argument value apart
+/// from an option-like `-x`/`--flag` token, not for sign-testing an already
+/// parsed number.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_negative_number;
+///
+/// assert!(is_negative_number("-1"));
+/// assert!(is_negative_number("-1.5"));
+/// assert!(is_negative_number("-.10"));
+///
+/// assert!(!is_negative_number("-"));
+/// assert!(!is_negative_number("--"));
+/// assert!(!is_negative_number("-abc"));
+/// assert!(!is_negative_number("--flag"));
+/// ```
+pub fn is_negative_number(value: &str) -> bool {
+    value.strip_prefix('-').is_some_and(is_numeric)
+}
+
+/// Determine if the value is an even integer.
+///
+/// Determine whether the provided value represents a valid integer that is
+/// evenly divisible by two.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_even;
+///
+/// assert!(is_even("2"));
+/// assert!(is_even("-4"));
+/// assert!(is_even("0"));
+///
+/// assert!(!is_even("1"));
+/// assert!(!is_even("1.0"));
+/// assert!(!is_even("a"));
+/// ```
+pub fn is_even(value: &str) -> bool {
+    value.parse::<i64>().map_or(false, |value| value % 2 == 0)
+}
+
+/// Determine if the value is an odd integer.
+///
+/// Determine whether the provided value represents a valid integer that is
+/// not evenly divisible by two.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_odd;
+///
+/// assert!(is_odd("1"));
+/// assert!(is_odd("-3"));
+///
+/// assert!(!is_odd("2"));
+/// assert!(!is_odd("1.0"));
+/// assert!(!is_odd("a"));
+/// ```
+pub fn is_odd(value: &str) -> bool {
+    value.parse::<i64>().map_or(false, |value| value % 2 != 0)
+}
+
+/// Determine if the value is divisible by a divisor.
+///
+/// Determine whether the provided value represents a valid integer that is
+/// evenly divisible by `divisor`. Returns false if `divisor` is zero.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_divisible_by;
+///
+/// assert!(is_divisible_by("10", 5));
+/// assert!(is_divisible_by("-10", 5));
+///
+/// assert!(!is_divisible_by("10", 3));
+/// assert!(!is_divisible_by("10", 0));
+/// assert!(!is_divisible_by("a", 5));
+/// ```
+pub fn is_divisible_by(value: &str, divisor: i64) -> bool {
+    if divisor == 0 {
+        return false;
+    }
+
+    value.parse::<i64>().map_or(false, |value| value % divisor == 0)
+}
+
+/// Determine if the value is a number within a range.
+///
+/// Determine whether the provided value represents a valid number falling
+/// within the inclusive range `min..=max`.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_in_range;
+///
+/// assert!(is_in_range("5", 0.0, 10.0));
+/// assert!(is_in_range("0", 0.0, 10.0));
+/// assert!(is_in_range("10", 0.0, 10.0));
+///
+/// assert!(!is_in_range("-1", 0.0, 10.0));
+/// assert!(!is_in_range("11", 0.0, 10.0));
+/// assert!(!is_in_range("5", 10.0, 0.0));
+/// assert!(!is_in_range("a", 0.0, 10.0));
+/// ```
+pub fn is_in_range(value: &str, min: f64, max: f64) -> bool {
+    value
+        .parse::<f64>()
+        .map_or(false, |value| value >= min && value <= max)
+}
+
+/// Determine if the value's length is within a range.
+///
+/// Determine whether the provided value's character count falls within the
+/// inclusive range `min..=max`.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::is_length;
+///
+/// assert!(is_length("abc", 1, 5));
+/// assert!(is_length("abc", 3, 3));
+/// assert!(is_length("", 0, 5));
+///
+/// assert!(!is_length("abc", 4, 5));
+/// assert!(!is_length("abcdef", 1, 5));
+/// assert!(!is_length("abc", 5, 1));
+/// ```
+pub fn is_length(value: &str, min: usize, max: usize) -> bool {
+    let length = value.chars().count();
+
+    length >= min && length <= max
+}
+
+/// Strip the "Error: " prefix and trailing period Display adds, so a
+/// Validator's failure can be quoted inside a larger message without
+/// doubling them up.
+fn reason(error: &Error) -> String {
+    error
+        .to_string()
+        .trim_start_matches("Error: ")
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Validator.
+///
+/// A composable validation rule for a string value. Blanket-implemented for
+/// every `Fn(&str) -> bool`, so any predicate in this module already
+/// implements Validator. Use `and`, `or` and `not` to build a composite
+/// Validator that reports which rule actually failed, rather than a bare
+/// boolean.
+///
+/// # Example
+/// ```rust
+/// use cherry::validate::{is_alphanumeric, is_length, Validator};
+///
+/// let username = is_alphanumeric.and(|value: &str| is_length(value, 3, 20));
+///
+/// assert!(username.validate("cherry").is_ok());
+/// assert!(username.validate("c!").is_err());
+/// ```
+pub trait Validator {
+    /// Validate a value, returning a descriptive Error on failure.
+    fn validate(&self, value: &str) -> error::Result<()>;
+
+    /// Require both this Validator and `other` to pass.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::validate::{is_alphanumeric, is_length, Validator};
+    ///
+    /// let rule = is_alphanumeric.and(|value: &str| is_length(value, 3, 20));
+    ///
+    /// assert!(rule.validate("abc").is_ok());
+    /// ```
+    fn and<V>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+        V: Validator,
+    {
+        And {
+            left: self,
+            right: other,
+        }
+    }
+
+    /// Require either this Validator or `other` to pass.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::validate::{is_integer, Validator};
+    ///
+    /// let rule = is_integer.or(|value: &str| value.is_empty());
+    ///
+    /// assert!(rule.validate("").is_ok());
+    /// ```
+    fn or<V>(self, other: V) -> Or<Self, V>
+    where
+        Self: Sized,
+        V: Validator,
+    {
+        Or {
+            left: self,
+            right: other,
+        }
+    }
+
+    /// Require this Validator to fail.
+    ///
+    /// # Example
+    /// ```rust
+    /// use cherry::validate::{is_integer, Validator};
+    ///
+    /// let rule = is_integer.not();
+    ///
+    /// assert!(rule.validate("abc").is_ok());
+    /// assert!(rule.validate("1").is_err());
+    /// ```
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> bool,
+{
+    fn validate(&self, value: &str) -> error::Result<()> {
+        if self(value) {
+            Ok(())
+        } else {
+            Err(Error::invalid_value("value", value, "failed validation"))
+        }
+    }
+}
+
+/// And.
+///
+/// A Validator requiring both `left` and `right` to pass, built with
+/// `Validator::and`.
+pub struct And<L, R> {
+    /// The first Validator, checked first.
+    left: L,
+    /// The second Validator, checked only if `left` passes.
+    right: R,
+}
+
+impl<L, R> Validator for And<L, R>
+where
+    L: Validator,
+    R: Validator,
+{
+    fn validate(&self, value: &str) -> error::Result<()> {
+        self.left.validate(value)?;
+        self.right.validate(value)
+    }
+}
+
+/// Or.
+///
+/// A Validator requiring either `left` or `right` to pass, built with
+/// `Validator::or`.
+pub struct Or<L, R> {
+    /// The first Validator, checked first.
+    left: L,
+    /// The second Validator, checked only if `left` fails.
+    right: R,
+}
+
+impl<L, R> Validator for Or<L, R>
+where
+    L: Validator,
+    R: Validator,
+{
+    fn validate(&self, value: &str) -> error::Result<()> {
+        match self.left.validate(value) {
+            Ok(()) => Ok(()),
+            Err(left_error) => match self.right.validate(value) {
+                Ok(()) => Ok(()),
+                Err(right_error) => Err(Error::invalid_value(
+                    "value",
+                    value,
+                    &format!("{} and {}", reason(&left_error), reason(&right_error)),
+                )),
+            },
+        }
+    }
+}
+
+/// Not.
+///
+/// A Validator requiring the wrapped Validator to fail, built with
+/// `Validator::not`.
+pub struct Not<V>(V);
+
+impl<V> Validator for Not<V>
+where
+    V: Validator,
+{
+    fn validate(&self, value: &str) -> error::Result<()> {
+        match self.0.validate(value) {
+            Ok(()) => Err(Error::invalid_value(
+                "value",
+                value,
+                "must not satisfy the negated rule",
+            )),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -341,4 +665,511 @@ mod tests {
     fn is_positive_non_number() {
         assert!(!is_positive("a"));
     }
+
+    /// Method is_negative_number must pass on a negative integer.
+    ///
+    /// If provided a hyphen followed by an integer, is_negative_number must
+    /// return true.
+    #[test]
+    fn is_negative_number_integer() {
+        assert!(is_negative_number("-1"));
+    }
+
+    /// Method is_negative_number must pass on a negative float.
+    ///
+    /// If provided a hyphen followed by a float, is_negative_number must
+    /// return true.
+    #[test]
+    fn is_negative_number_float() {
+        assert!(is_negative_number("-1.5"));
+    }
+
+    /// Method is_negative_number must pass on a negative dot number.
+    ///
+    /// If provided a hyphen followed by a dot character followed by a
+    /// number, is_negative_number must return true.
+    #[test]
+    fn is_negative_number_dot_number() {
+        assert!(is_negative_number("-.10"));
+    }
+
+    /// Method is_negative_number must pass on negative zero.
+    ///
+    /// If provided a hyphen followed by zero, is_negative_number must
+    /// return true, even though the value's sign is not actually negative.
+    #[test]
+    fn is_negative_number_zero() {
+        assert!(is_negative_number("-0"));
+    }
+
+    /// Method is_negative_number must pass on a dot zero.
+    ///
+    /// If provided a hyphen followed by a dot character followed by zero,
+    /// is_negative_number must return true.
+    #[test]
+    fn is_negative_number_dot_zero() {
+        assert!(is_negative_number("-.0"));
+    }
+
+    /// Method is_negative_number must pass on a zero dot.
+    ///
+    /// If provided a hyphen followed by a zero followed by a dot character,
+    /// is_negative_number must return true.
+    #[test]
+    fn is_negative_number_zero_dot() {
+        assert!(is_negative_number("-0."));
+    }
+
+    /// Method is_negative_number must fail on a lone hyphen.
+    ///
+    /// If provided only a hyphen, is_negative_number must return false.
+    #[test]
+    fn is_negative_number_lone_hyphen() {
+        assert!(!is_negative_number("-"));
+    }
+
+    /// Method is_negative_number must fail on a double hyphen.
+    ///
+    /// If provided a double hyphen, is_negative_number must return false,
+    /// so a long flag token like `--flag` isn't misread as a value.
+    #[test]
+    fn is_negative_number_double_hyphen() {
+        assert!(!is_negative_number("--"));
+    }
+
+    /// Method is_negative_number must fail on a hyphen followed by letters.
+    ///
+    /// If provided a hyphen followed by non numeric characters,
+    /// is_negative_number must return false.
+    #[test]
+    fn is_negative_number_non_number() {
+        assert!(!is_negative_number("-abc"));
+    }
+
+    /// Method is_negative_number must fail on a long flag token.
+    ///
+    /// If provided a long flag token, is_negative_number must return false.
+    #[test]
+    fn is_negative_number_flag() {
+        assert!(!is_negative_number("--flag"));
+    }
+
+    /// Method is_negative_number must fail on a positive number.
+    ///
+    /// If provided a value with no leading hyphen, is_negative_number must
+    /// return false.
+    #[test]
+    fn is_negative_number_positive() {
+        assert!(!is_negative_number("1"));
+    }
+
+    /// Method is_even must pass on an even integer.
+    ///
+    /// If provided an even integer, is_even must return true.
+    #[test]
+    fn is_even_true() {
+        assert!(is_even("2"));
+    }
+
+    /// Method is_even must pass on a negative even integer.
+    ///
+    /// If provided a negative even integer, is_even must return true.
+    #[test]
+    fn is_even_negative() {
+        assert!(is_even("-4"));
+    }
+
+    /// Method is_even must pass on zero.
+    ///
+    /// If provided zero, is_even must return true.
+    #[test]
+    fn is_even_zero() {
+        assert!(is_even("0"));
+    }
+
+    /// Method is_even must fail on an odd integer.
+    ///
+    /// If provided an odd integer, is_even must return false.
+    #[test]
+    fn is_even_odd() {
+        assert!(!is_even("1"));
+    }
+
+    /// Method is_even must fail on a float.
+    ///
+    /// If provided a floating point number, is_even must return false.
+    #[test]
+    fn is_even_float() {
+        assert!(!is_even("2.0"));
+    }
+
+    /// Method is_even must fail on a non number.
+    ///
+    /// If provided a non number token, is_even must return false.
+    #[test]
+    fn is_even_non_number() {
+        assert!(!is_even("a"));
+    }
+
+    /// Method is_even must fail on an empty string.
+    ///
+    /// If provided an empty string, is_even must return false.
+    #[test]
+    fn is_even_empty() {
+        assert!(!is_even(""));
+    }
+
+    /// Method is_odd must pass on an odd integer.
+    ///
+    /// If provided an odd integer, is_odd must return true.
+    #[test]
+    fn is_odd_true() {
+        assert!(is_odd("1"));
+    }
+
+    /// Method is_odd must pass on a negative odd integer.
+    ///
+    /// If provided a negative odd integer, is_odd must return true.
+    #[test]
+    fn is_odd_negative() {
+        assert!(is_odd("-3"));
+    }
+
+    /// Method is_odd must fail on an even integer.
+    ///
+    /// If provided an even integer, is_odd must return false.
+    #[test]
+    fn is_odd_even() {
+        assert!(!is_odd("2"));
+    }
+
+    /// Method is_odd must fail on a float.
+    ///
+    /// If provided a floating point number, is_odd must return false.
+    #[test]
+    fn is_odd_float() {
+        assert!(!is_odd("1.0"));
+    }
+
+    /// Method is_odd must fail on a non number.
+    ///
+    /// If provided a non number token, is_odd must return false.
+    #[test]
+    fn is_odd_non_number() {
+        assert!(!is_odd("a"));
+    }
+
+    /// Method is_odd must fail on an empty string.
+    ///
+    /// If provided an empty string, is_odd must return false.
+    #[test]
+    fn is_odd_empty() {
+        assert!(!is_odd(""));
+    }
+
+    /// Method is_divisible_by must pass on a divisible integer.
+    ///
+    /// If provided an integer evenly divisible by divisor, is_divisible_by
+    /// must return true.
+    #[test]
+    fn is_divisible_by_true() {
+        assert!(is_divisible_by("10", 5));
+    }
+
+    /// Method is_divisible_by must pass on a negative divisible integer.
+    ///
+    /// If provided a negative integer evenly divisible by divisor,
+    /// is_divisible_by must return true.
+    #[test]
+    fn is_divisible_by_negative() {
+        assert!(is_divisible_by("-10", 5));
+    }
+
+    /// Method is_divisible_by must fail on a non divisible integer.
+    ///
+    /// If provided an integer not evenly divisible by divisor,
+    /// is_divisible_by must return false.
+    #[test]
+    fn is_divisible_by_false() {
+        assert!(!is_divisible_by("10", 3));
+    }
+
+    /// Method is_divisible_by must fail on a zero divisor.
+    ///
+    /// If provided a divisor of zero, is_divisible_by must return false.
+    #[test]
+    fn is_divisible_by_zero_divisor() {
+        assert!(!is_divisible_by("10", 0));
+    }
+
+    /// Method is_divisible_by must fail on a float.
+    ///
+    /// If provided a floating point number, is_divisible_by must return
+    /// false.
+    #[test]
+    fn is_divisible_by_float() {
+        assert!(!is_divisible_by("10.0", 5));
+    }
+
+    /// Method is_divisible_by must fail on a non number.
+    ///
+    /// If provided a non number token, is_divisible_by must return false.
+    #[test]
+    fn is_divisible_by_non_number() {
+        assert!(!is_divisible_by("a", 5));
+    }
+
+    /// Method is_divisible_by must fail on an empty string.
+    ///
+    /// If provided an empty string, is_divisible_by must return false.
+    #[test]
+    fn is_divisible_by_empty() {
+        assert!(!is_divisible_by("", 5));
+    }
+
+    /// Method is_in_range must pass on a value within range.
+    ///
+    /// If provided a value strictly between min and max, is_in_range must
+    /// return true.
+    #[test]
+    fn is_in_range_within() {
+        assert!(is_in_range("5", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must pass on the minimum bound.
+    ///
+    /// If provided a value equal to min, is_in_range must return true.
+    #[test]
+    fn is_in_range_min_bound() {
+        assert!(is_in_range("0", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must pass on the maximum bound.
+    ///
+    /// If provided a value equal to max, is_in_range must return true.
+    #[test]
+    fn is_in_range_max_bound() {
+        assert!(is_in_range("10", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must fail below the minimum.
+    ///
+    /// If provided a value below min, is_in_range must return false.
+    #[test]
+    fn is_in_range_below() {
+        assert!(!is_in_range("-1", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must fail above the maximum.
+    ///
+    /// If provided a value above max, is_in_range must return false.
+    #[test]
+    fn is_in_range_above() {
+        assert!(!is_in_range("11", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must fail when min is greater than max.
+    ///
+    /// If provided a min greater than max, no value can satisfy the range,
+    /// so is_in_range must return false.
+    #[test]
+    fn is_in_range_min_greater_than_max() {
+        assert!(!is_in_range("5", 10.0, 0.0));
+    }
+
+    /// Method is_in_range must fail on a non number.
+    ///
+    /// If provided a non number token, is_in_range must return false.
+    #[test]
+    fn is_in_range_non_number() {
+        assert!(!is_in_range("a", 0.0, 10.0));
+    }
+
+    /// Method is_in_range must fail on an empty string.
+    ///
+    /// If provided an empty string, is_in_range must return false.
+    #[test]
+    fn is_in_range_empty() {
+        assert!(!is_in_range("", 0.0, 10.0));
+    }
+
+    /// Method is_length must pass on a length within range.
+    ///
+    /// If provided a value whose character count is strictly between min and
+    /// max, is_length must return true.
+    #[test]
+    fn is_length_within() {
+        assert!(is_length("abc", 1, 5));
+    }
+
+    /// Method is_length must pass on the minimum bound.
+    ///
+    /// If provided a value whose character count equals min, is_length must
+    /// return true.
+    #[test]
+    fn is_length_min_bound() {
+        assert!(is_length("abc", 3, 5));
+    }
+
+    /// Method is_length must pass on the maximum bound.
+    ///
+    /// If provided a value whose character count equals max, is_length must
+    /// return true.
+    #[test]
+    fn is_length_max_bound() {
+        assert!(is_length("abc", 1, 3));
+    }
+
+    /// Method is_length must pass on an empty string within range.
+    ///
+    /// If provided an empty string with min of zero, is_length must return
+    /// true.
+    #[test]
+    fn is_length_empty_within_range() {
+        assert!(is_length("", 0, 5));
+    }
+
+    /// Method is_length must fail below the minimum.
+    ///
+    /// If provided a value whose character count is below min, is_length
+    /// must return false.
+    #[test]
+    fn is_length_below() {
+        assert!(!is_length("abc", 4, 5));
+    }
+
+    /// Method is_length must fail above the maximum.
+    ///
+    /// If provided a value whose character count is above max, is_length
+    /// must return false.
+    #[test]
+    fn is_length_above() {
+        assert!(!is_length("abcdef", 1, 5));
+    }
+
+    /// Method is_length must fail when min is greater than max.
+    ///
+    /// If provided a min greater than max, no value can satisfy the range,
+    /// so is_length must return false.
+    #[test]
+    fn is_length_min_greater_than_max() {
+        assert!(!is_length("abc", 5, 1));
+    }
+
+    /// A bare predicate must implement Validator and pass on a valid value.
+    ///
+    /// Any `Fn(&str) -> bool` must implement Validator via the blanket impl,
+    /// returning Ok when the predicate returns true.
+    #[test]
+    fn validator_predicate_ok() {
+        assert!(is_integer.validate("1").is_ok());
+    }
+
+    /// A bare predicate must implement Validator and fail on an invalid value.
+    ///
+    /// Any `Fn(&str) -> bool` must implement Validator via the blanket impl,
+    /// returning Err when the predicate returns false.
+    #[test]
+    fn validator_predicate_err() {
+        assert!(is_integer.validate("a").is_err());
+    }
+
+    /// Validator::and must pass when both rules pass.
+    ///
+    /// An And combinator must return Ok when both the left and right
+    /// Validator pass.
+    #[test]
+    fn validator_and_both_pass() {
+        let rule = is_alphanumeric.and(|value: &str| is_length(value, 3, 20));
+
+        assert!(rule.validate("abc").is_ok());
+    }
+
+    /// Validator::and must fail when the left rule fails.
+    ///
+    /// An And combinator must return Err, identifying the left Validator's
+    /// failure, when the left Validator fails.
+    #[test]
+    fn validator_and_left_fails() {
+        let rule = is_alphanumeric.and(|value: &str| is_length(value, 3, 20));
+
+        assert!(rule.validate("a!").is_err());
+    }
+
+    /// Validator::and must fail when the right rule fails.
+    ///
+    /// An And combinator must return Err when the right Validator fails,
+    /// even though the left Validator passes.
+    #[test]
+    fn validator_and_right_fails() {
+        let rule = is_alphanumeric.and(|value: &str| is_length(value, 3, 20));
+
+        assert!(rule.validate("ab").is_err());
+    }
+
+    /// Validator::or must pass when the left rule passes.
+    ///
+    /// An Or combinator must return Ok when the left Validator passes,
+    /// without requiring the right Validator to pass.
+    #[test]
+    fn validator_or_left_passes() {
+        let rule = is_integer.or(|value: &str| value.is_empty());
+
+        assert!(rule.validate("1").is_ok());
+    }
+
+    /// Validator::or must pass when the right rule passes.
+    ///
+    /// An Or combinator must return Ok when the right Validator passes,
+    /// even though the left Validator fails.
+    #[test]
+    fn validator_or_right_passes() {
+        let rule = is_integer.or(|value: &str| value.is_empty());
+
+        assert!(rule.validate("").is_ok());
+    }
+
+    /// Validator::or must fail when both rules fail.
+    ///
+    /// An Or combinator must return Err, describing both failures, when
+    /// neither the left nor right Validator passes.
+    #[test]
+    fn validator_or_both_fail() {
+        let rule = is_integer.or(|value: &str| value.is_empty());
+
+        assert!(rule.validate("abc").is_err());
+    }
+
+    /// Validator::not must pass when the inner rule fails.
+    ///
+    /// A Not combinator must return Ok when the wrapped Validator fails.
+    #[test]
+    fn validator_not_inner_fails() {
+        let rule = is_integer.not();
+
+        assert!(rule.validate("abc").is_ok());
+    }
+
+    /// Validator::not must fail when the inner rule passes.
+    ///
+    /// A Not combinator must return Err when the wrapped Validator passes.
+    #[test]
+    fn validator_not_inner_passes() {
+        let rule = is_integer.not();
+
+        assert!(rule.validate("1").is_err());
+    }
+
+    /// Validators must compose across and/or/not.
+    ///
+    /// Combinators must nest freely, so a rule built from and/or/not
+    /// together still reports a meaningful pass/fail outcome.
+    #[test]
+    fn validator_composes_and_or_not() {
+        let rule = is_integer.or(is_integer.not().and(|value: &str| is_length(value, 1, 3)));
+
+        assert!(rule.validate("1").is_ok());
+        assert!(rule.validate("abc").is_ok());
+        assert!(rule.validate("abcd").is_err());
+    }
 }